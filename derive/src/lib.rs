@@ -0,0 +1,278 @@
+//! Derive and attribute macros for `super_cell`, re-exported from it behind the `derive` feature
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Error, Fields, GenericParam, Index, ItemStruct, Lifetime, LifetimeParam};
+
+/// Implements `super_cell::CellProject`, splitting a `SuperCell<Struct>` into one cell per field
+///
+/// For a struct `Player` this generates a `PlayerCells<'a>` view with the same visibility and
+/// generics, holding a `&'a SuperCell<Field>` for every field under the same name and
+/// visibility, or at the same index for tuple structs. The view is returned by
+/// `SuperCell::fields` and is `Copy`. Fields are projected through raw pointers like
+/// `super_cell::project!`, so all of them can be used at the same time. Fields marked
+/// `#[cell_project(skip)]` are left out of the view, or replaced by `()` in tuple structs.
+///
+/// # Examples
+///
+/// ```
+/// use super_cell::{CellProject, SuperCell};
+/// #[derive(CellProject)]
+/// struct Player {
+///     hp : u32,
+///     name : String
+/// }
+/// let player = SuperCell::new(Player { hp : 10, name : String::from("a") });
+/// let PlayerCells { hp, name, .. } = player.fields();
+/// *hp.get_mut() -= 1;
+/// name.get_mut().push('b');
+/// assert_eq!(player.get().hp, 9);
+/// assert_eq!(player.get().name, "ab");
+/// ```
+///
+/// Skipped fields have no cell:
+///
+/// ```compile_fail
+/// use super_cell::{CellProject, SuperCell};
+/// #[derive(CellProject)]
+/// struct Player {
+///     #[cell_project(skip)]
+///     hp : u32
+/// }
+/// let player = SuperCell::new(Player { hp : 10 });
+/// player.fields().hp;
+/// ```
+///
+/// Only structs can be projected:
+///
+/// ```compile_fail
+/// use super_cell::CellProject;
+/// #[derive(CellProject)]
+/// enum State { On, Off }
+/// ```
+///
+/// Nor packed structs, whose fields may be unaligned:
+///
+/// ```compile_fail
+/// use super_cell::CellProject;
+/// #[derive(CellProject)]
+/// #[repr(packed)]
+/// struct Header { kind : u8, len : u32 }
+/// ```
+#[proc_macro_derive(CellProject, attributes(cell_project))]
+pub fn derive_cell_project(input : TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    cell_project(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn cell_project(input : DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(Span::call_site(), "`CellProject` can only be derived for structs"));
+    };
+    reject_packed(&input.attrs)?;
+    let ident = &input.ident;
+    let cells = format_ident!("{}Cells", ident);
+    let vis = &input.vis;
+    let lifetime : Lifetime = parse_quote!('__cell);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut view_generics = input.generics.clone();
+    view_generics.params.insert(0, GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())));
+    let (view_impl_generics, view_ty_generics, _) = view_generics.split_for_impl();
+    let marker = quote!(::core::marker::PhantomData<&#lifetime ::super_cell::SuperCell<#ident #ty_generics>>);
+
+    let mut definitions = Vec::new();
+    let mut projections = Vec::new();
+    for (index, field) in data.fields.iter().enumerate() {
+        let skipped = is_skipped(&field.attrs, "cell_project")?;
+        let field_vis = &field.vis;
+        let ty = &field.ty;
+        match &field.ident {
+            Some(_) if skipped => {}
+            Some(name) => {
+                definitions.push(quote!(#field_vis #name : &#lifetime ::super_cell::SuperCell<#ty>));
+                projections.push(quote!(#name : ::super_cell::project!(cell => #name)));
+            }
+            None if skipped => {
+                definitions.push(quote!(#field_vis ()));
+                projections.push(quote!(()));
+            }
+            None => {
+                let index = Index::from(index);
+                definitions.push(quote!(#field_vis &#lifetime ::super_cell::SuperCell<#ty>));
+                projections.push(quote!(::super_cell::project!(cell => #index)));
+            }
+        }
+    }
+    let doc = format!("Cells of the fields of a `SuperCell<{ident}>`, returned by `SuperCell::fields`");
+    let (definition, construction) = match &data.fields {
+        Fields::Named(_) => (
+            quote!(#vis struct #cells #view_generics #where_clause { #(#definitions,)* _marker : #marker }),
+            quote!(#cells { #(#projections,)* _marker : ::core::marker::PhantomData })
+        ),
+        Fields::Unnamed(_) | Fields::Unit => (
+            quote!(#vis struct #cells #view_generics (#(#definitions,)* #marker) #where_clause;),
+            quote!(#cells(#(#projections,)* ::core::marker::PhantomData))
+        )
+    };
+    Ok(quote! {
+        #[doc = #doc]
+        #definition
+
+        impl #view_impl_generics ::core::clone::Clone for #cells #view_ty_generics #where_clause {
+            #[inline]
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl #view_impl_generics ::core::marker::Copy for #cells #view_ty_generics #where_clause {}
+
+        impl #impl_generics ::super_cell::CellProject for #ident #ty_generics #where_clause {
+            type Cells<#lifetime> = #cells #view_ty_generics where Self : #lifetime;
+
+            #[inline]
+            fn project(cell : &::super_cell::SuperCell<Self>) -> Self::Cells<'_> {
+                #construction
+            }
+        }
+    })
+}
+
+/// Wraps every field of a struct in a `SuperCell`, generating a constructor and a plain twin
+///
+/// For a struct `Config`, the field types become `SuperCell<Field>` and a `ConfigPlain` struct
+/// is generated with the original field types, the same attributes and the same visibility.
+/// `Config` gets `new`, taking the plain field values in order, plus `into_plain` and
+/// `from_plain`. Fields marked `#[cellify(skip)]` keep their type in both structs.
+///
+/// Derives listed after the attribute apply to both structs, so `#[cellify]` must come before
+/// them. Since `SuperCell` serializes as its value, both structs share a serde representation.
+///
+/// # Examples
+///
+/// ```
+/// #[super_cell::cellify]
+/// #[derive(Debug, PartialEq)]
+/// struct Config {
+///     retries : u32,
+///     name : String,
+///     #[cellify(skip)]
+///     id : u64
+/// }
+/// let config = Config::new(3, String::from("a"), 7);
+/// config.retries.set(4);
+/// config.name.get_mut().push('b');
+/// assert_eq!(config.into_plain(), ConfigPlain { retries : 4, name : String::from("ab"), id : 7 });
+/// ```
+///
+/// Only structs with named fields are supported:
+///
+/// ```compile_fail
+/// #[super_cell::cellify]
+/// struct Meters(f64);
+/// ```
+#[proc_macro_attribute]
+pub fn cellify(args : TokenStream, input : TokenStream) -> TokenStream {
+    let args = proc_macro2::TokenStream::from(args);
+    if !args.is_empty() {
+        return Error::new_spanned(args, "`cellify` takes no arguments").into_compile_error().into();
+    }
+    let input = parse_macro_input!(input as ItemStruct);
+    cellify_struct(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn cellify_struct(mut input : ItemStruct) -> syn::Result<proc_macro2::TokenStream> {
+    let Fields::Named(fields) = &mut input.fields else {
+        return Err(Error::new(Span::call_site(), "`cellify` only supports structs with named fields"));
+    };
+    let mut plain = Vec::new();
+    let mut parameters = Vec::new();
+    let mut wrap = Vec::new();
+    let mut unwrap = Vec::new();
+    for field in fields.named.iter_mut() {
+        let skipped = is_skipped(&field.attrs, "cellify")?;
+        field.attrs.retain(|attr| !attr.path().is_ident("cellify"));
+        plain.push(field.clone());
+        let name = field.ident.as_ref().expect("named fields have names");
+        let ty = &field.ty;
+        parameters.push(quote!(#name : #ty));
+        if skipped {
+            wrap.push(quote!(#name));
+            unwrap.push(quote!(#name : self.#name));
+        } else {
+            wrap.push(quote!(#name : ::super_cell::SuperCell::new(#name)));
+            unwrap.push(quote!(#name : self.#name.into_inner()));
+            field.ty = parse_quote!(::super_cell::SuperCell<#ty>);
+        }
+    }
+    let brace_token = fields.brace_token;
+    let names : Vec<_> = plain.iter().map(|field| field.ident.clone()).collect();
+    let ident = &input.ident;
+    let plain_ident = format_ident!("{}Plain", ident);
+    let mut twin = input.clone();
+    twin.ident = plain_ident.clone();
+    twin.fields = Fields::Named(syn::FieldsNamed { brace_token, named : plain.into_iter().collect() });
+    let vis = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        #input
+
+        #twin
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Creates the struct from plain field values, in declaration order
+            #[inline]
+            #[allow(clippy::too_many_arguments)]
+            #vis const fn new(#(#parameters),*) -> Self {
+                Self { #(#wrap),* }
+            }
+
+            /// Unwraps every field into the plain twin
+            #[inline]
+            #vis fn into_plain(self) -> #plain_ident #ty_generics {
+                #plain_ident { #(#unwrap),* }
+            }
+
+            /// Wraps every field of the plain twin
+            #[inline]
+            #vis fn from_plain(plain : #plain_ident #ty_generics) -> Self {
+                let #plain_ident { #(#names),* } = plain;
+                Self::new(#(#names),*)
+            }
+        }
+    })
+}
+
+/// Fails on `#[repr(packed)]`, whose fields may be unaligned and cannot be borrowed as cells
+fn reject_packed(attrs : &[Attribute]) -> syn::Result<()> {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("packed") {
+                return Err(meta.error("`CellProject` cannot be derived for `#[repr(packed)]` structs"));
+            }
+            // Skips the arguments of other hints, like `align(8)`.
+            if meta.input.peek(syn::token::Paren) {
+                meta.input.parse::<proc_macro2::TokenTree>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(())
+}
+
+/// Returns `true` if the attributes hold `#[name(skip)]`, rejecting any other argument
+fn is_skipped(attrs : &[Attribute], name : &str) -> syn::Result<bool> {
+    let mut skipped = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident(name)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skipped = true;
+                Ok(())
+            } else {
+                Err(meta.error(format!("unknown `{name}` argument, expected `skip`")))
+            }
+        })?;
+    }
+    Ok(skipped)
+}
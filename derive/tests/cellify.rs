@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+use super_cell::{cellify, SuperCell};
+
+#[cellify]
+#[derive(Debug, PartialEq)]
+struct Stack<T : Clone, const N : usize> where T : Default {
+    items : [T; N],
+    #[cellify(skip)]
+    capacity : usize
+}
+
+#[cellify]
+#[derive(Debug, PartialEq)]
+struct Limits {
+    low : u32,
+    high : u32
+}
+
+#[cellify]
+#[derive(Debug, PartialEq)]
+struct Settings {
+    name : String,
+    limits : Limits
+}
+
+#[cellify]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Config {
+    #[serde(rename = "tries")]
+    retries : u32,
+    #[serde(default)]
+    tags : Vec<String>
+}
+
+#[test]
+fn generics() {
+    let stack = Stack::new([1u8, 2], 2);
+    stack.items.get_mut()[0] = 3;
+    let plain : StackPlain<u8, 2> = stack.into_plain();
+    assert_eq!(plain, StackPlain { items : [3, 2], capacity : 2 });
+    let stack = Stack::from_plain(plain);
+    assert_eq!(stack.capacity, 2);
+    assert_eq!(*stack.items.get(), [3, 2]);
+}
+
+#[test]
+fn nested() {
+    let settings = Settings::new(String::from("a"), Limits::new(1, 2));
+    let limits : &SuperCell<Limits> = &settings.limits;
+    limits.get().high.set(5);
+    limits.get().low.set(limits.get().high.get() - 1);
+    settings.name.get_mut().push('b');
+    let plain = settings.into_plain();
+    assert_eq!(plain.name, "ab");
+    assert_eq!(plain.limits.into_plain(), LimitsPlain { low : 4, high : 5 });
+}
+
+#[test]
+fn serde_coexists() {
+    let config = Config::new(3, vec![String::from("a")]);
+    config.retries.set(4);
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(json, r#"{"tries":4,"tags":["a"]}"#);
+    let plain : ConfigPlain = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string(&plain).unwrap(), json);
+    let config : Config = serde_json::from_str(r#"{"tries":1}"#).unwrap();
+    assert_eq!(config.into_plain(), ConfigPlain { retries : 1, tags : vec![] });
+}
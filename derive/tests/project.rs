@@ -0,0 +1,95 @@
+use super_cell::{CellProject, SuperCell};
+
+#[derive(CellProject, Debug, PartialEq)]
+struct Vec3 {
+    x : f32,
+    y : f32,
+    z : f32
+}
+
+#[derive(CellProject)]
+struct Player {
+    hp : u32,
+    position : Vec3,
+    #[cell_project(skip)]
+    #[allow(dead_code)]
+    id : u8
+}
+
+#[derive(CellProject)]
+struct Pair<A, B : Clone> where A : Default {
+    left : A,
+    right : Vec<B>
+}
+
+#[derive(CellProject)]
+#[allow(dead_code)]
+struct Meters(f64, #[cell_project(skip)] u8, &'static str);
+
+#[derive(CellProject)]
+#[repr(C, align(16))]
+struct Header {
+    kind : u8,
+    len : u32
+}
+
+mod private {
+    use super_cell::CellProject;
+
+    #[derive(CellProject)]
+    pub struct Account {
+        pub owner : String,
+        pub(crate) balance : u64
+    }
+}
+
+#[test]
+fn simultaneous_fields() {
+    let player = SuperCell::new(Player { hp : 10, position : Vec3 { x : 0.0, y : 0.0, z : 0.0 }, id : 0 });
+    let fields = player.fields();
+    let position = fields.position.fields();
+    *fields.hp.get_mut() -= 3;
+    *position.x.get_mut() += 1.5;
+    *position.z.get_mut() = 2.0;
+    *fields.hp.get_mut() -= 1;
+    assert_eq!(player.get().hp, 6);
+    assert_eq!(player.get().position, Vec3 { x : 1.5, y : 0.0, z : 2.0 });
+}
+
+#[test]
+fn generic_struct() {
+    let pair = SuperCell::new(Pair { left : 1u32, right : vec!['a'] });
+    let PairCells { left, right, .. } = pair.fields();
+    *left.get_mut() += 1;
+    right.get_mut().push('b');
+    assert_eq!(pair.get().left, 2);
+    assert_eq!(pair.get().right, ['a', 'b']);
+}
+
+#[test]
+fn tuple_struct() {
+    let meters = SuperCell::new(Meters(1.0, 0, "m"));
+    let MetersCells(value, (), unit, _) = meters.fields();
+    *value.get_mut() *= 100.0;
+    unit.set("cm");
+    assert_eq!((meters.get().0, meters.get().2), (100.0, "cm"));
+}
+
+#[test]
+fn visibility() {
+    let account = SuperCell::new(private::Account { owner : String::from("a"), balance : 5 });
+    let fields = account.fields();
+    fields.owner.get_mut().push('b');
+    *fields.balance.get_mut() += 1;
+    assert_eq!(account.get().owner, "ab");
+    assert_eq!(account.get().balance, 6);
+}
+
+#[test]
+fn other_repr_hints() {
+    let header = SuperCell::new(Header { kind : 1, len : 0 });
+    let HeaderCells { kind, len, .. } = header.fields();
+    len.set(u32::from(*kind.get()) + 4);
+    assert_eq!(header.get().len, 5);
+    assert_eq!(header.as_ptr() as usize % 16, 0);
+}
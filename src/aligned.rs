@@ -0,0 +1,129 @@
+use core::ops::Deref;
+use crate::SuperCell;
+
+/// Alignments available for [`AlignedCell`], through a zero sized marker with that alignment
+pub trait SupportedAlignment {
+    #[doc(hidden)]
+    type Marker : Copy;
+
+    #[doc(hidden)]
+    const MARKER : Self::Marker;
+}
+
+/// Names an alignment in bytes, implementing [`SupportedAlignment`] for the supported ones
+pub struct Alignment<const ALIGN : usize>;
+
+macro_rules! impl_alignments {
+    ($($align:literal => $marker:ident),*) => {$(
+        #[doc(hidden)]
+        #[derive(Clone, Copy)]
+        #[repr(align($align))]
+        pub struct $marker;
+
+        impl SupportedAlignment for Alignment<$align> {
+            type Marker = $marker;
+            const MARKER : $marker = $marker;
+        }
+    )*};
+}
+
+impl_alignments!(16 => Align16, 32 => Align32, 64 => Align64, 128 => Align128, 4096 => Align4096);
+
+/// `SuperCell` whose value is aligned to `ALIGN` bytes, for buffers handed to DMA engines,
+/// SIMD loads or page granular APIs
+///
+/// Only 16, 32, 64, 128 and 4096 are supported, other alignments fail to compile. Dereferences
+/// to the inner `SuperCell`, so the whole cell API is available.
+///
+/// # Examples
+///
+/// ```
+/// use super_cell::AlignedCell;
+/// let buffer = AlignedCell::<_, 4096>::new([0u8; 4096]);
+/// buffer.get_mut()[0] = 1;
+/// assert_eq!(buffer.as_ptr() as usize % 4096, 0);
+/// ```
+///
+/// ```compile_fail
+/// use super_cell::AlignedCell;
+/// let buffer = AlignedCell::<_, 8>::new([0u8; 8]);
+/// ```
+#[repr(C)]
+pub struct AlignedCell<T : ?Sized, const ALIGN : usize> where Alignment<ALIGN> : SupportedAlignment {
+    _align : <Alignment<ALIGN> as SupportedAlignment>::Marker,
+    cell : SuperCell<T>
+}
+
+impl<T, const ALIGN : usize> AlignedCell<T, ALIGN> where Alignment<ALIGN> : SupportedAlignment {
+    #[inline]
+    pub const fn new(value : T) -> Self {
+        Self {
+            _align : <Alignment<ALIGN> as SupportedAlignment>::MARKER,
+            cell : SuperCell::new(value)
+        }
+    }
+
+    /// Consumes the cell, returning the wrapped value
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.cell.into_inner()
+    }
+}
+
+impl<T : ?Sized, const ALIGN : usize> AlignedCell<T, ALIGN> where Alignment<ALIGN> : SupportedAlignment {
+    /// Returns the inner `SuperCell`, for use with the slice and array helpers
+    #[inline]
+    pub fn as_super_cell(&self) -> &SuperCell<T> {
+        &self.cell
+    }
+}
+
+impl<T : ?Sized, const ALIGN : usize> Deref for AlignedCell<T, ALIGN> where Alignment<ALIGN> : SupportedAlignment {
+    type Target = SuperCell<T>;
+
+    #[inline]
+    fn deref(&self) -> &SuperCell<T> {
+        &self.cell
+    }
+}
+
+impl<T : Default, const ALIGN : usize> Default for AlignedCell<T, ALIGN> where Alignment<ALIGN> : SupportedAlignment {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check<const ALIGN : usize>() where Alignment<ALIGN> : SupportedAlignment {
+        assert_eq!(align_of::<AlignedCell<u8, ALIGN>>(), ALIGN);
+        let cells : Vec<_> = (0..3).map(|_| AlignedCell::<[u8; 3], ALIGN>::new([1, 2, 3])).collect();
+        for cell in &cells {
+            assert_eq!(cell.as_ptr() as usize % ALIGN, 0);
+            assert_eq!(cell.as_super_cell().as_ptr(), cell.as_ptr());
+        }
+        let boxed = Box::new(AlignedCell::<u64, ALIGN>::new(5));
+        assert_eq!(boxed.as_ptr() as usize % ALIGN, 0);
+    }
+
+    #[test]
+    fn supported_alignments() {
+        check::<16>();
+        check::<32>();
+        check::<64>();
+        check::<128>();
+        check::<4096>();
+    }
+
+    #[test]
+    fn forwards_cell_api() {
+        let cell = AlignedCell::<_, 64>::new([0u32; 4]);
+        for (index, value) in cell.as_array_of_cells().iter().enumerate() {
+            value.set(index as u32);
+        }
+        cell.get_mut()[0] = 7;
+        assert_eq!(cell.into_inner(), [7, 1, 2, 3]);
+    }
+}
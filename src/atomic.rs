@@ -0,0 +1,86 @@
+use core::mem::{align_of, size_of};
+#[allow(unused_imports)]
+use core::sync::atomic::*;
+use crate::SuperCell;
+
+/// Reinterprets a cell as the atomic type with the same layout
+///
+/// The layout check is a post-monomorphization error, so it only fires where a view is used on
+/// a target whose atomic has a stricter alignment than the plain integer, like `AtomicU64` on
+/// 32 bit x86.
+#[allow(dead_code)]
+#[inline(always)]
+fn view<T, A>(cell : &SuperCell<T>) -> &A {
+    const {
+        assert!(size_of::<T>() == size_of::<A>(), "atomic view of a different size");
+        assert!(align_of::<A>() <= align_of::<T>(), "atomic view needs a stricter alignment than the cell");
+    }
+    // SAFETY: The layouts were checked above and every value of the atomic is a valid `T`.
+    unsafe { &*cell.as_ptr().cast::<A>() }
+}
+
+macro_rules! impl_atomic_views {
+    ($($width:literal => $value:ty, $atomic:ty, $method:ident;)+) => {$(
+        #[cfg(target_has_atomic = $width)]
+        impl SuperCell<$value> {
+            #[doc = concat!("Returns an `", stringify!($atomic), "` view of the value")]
+            ///
+            /// Mixing atomic and plain access to the value at the same time is still a data race.
+            /// The view is for phases where every access goes through the atomic, with the plain
+            /// API used again only after something like a join orders it after them.
+            #[inline]
+            pub fn $method(&self) -> &$atomic {
+                view(self)
+            }
+        }
+    )+};
+}
+
+impl_atomic_views! {
+    "8" => u8, AtomicU8, as_atomic_u8;
+    "8" => i8, AtomicI8, as_atomic_i8;
+    "8" => bool, AtomicBool, as_atomic_bool;
+    "16" => u16, AtomicU16, as_atomic_u16;
+    "16" => i16, AtomicI16, as_atomic_i16;
+    "32" => u32, AtomicU32, as_atomic_u32;
+    "32" => i32, AtomicI32, as_atomic_i32;
+    "64" => u64, AtomicU64, as_atomic_u64;
+    "64" => i64, AtomicI64, as_atomic_i64;
+    "ptr" => usize, AtomicUsize, as_atomic_usize;
+    "ptr" => isize, AtomicIsize, as_atomic_isize;
+}
+
+#[cfg(all(test, target_has_atomic = "ptr"))]
+mod tests {
+    use std::thread;
+    use super::*;
+
+    #[test]
+    fn fetch_add_storm() {
+        let counter = SuperCell::new(0u32);
+        thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    for _ in 0..1000 {
+                        counter.as_atomic_u32().fetch_add(1, Ordering::Relaxed);
+                    }
+                });
+            }
+        });
+        assert_eq!(*counter.get(), 8000);
+        counter.set(1);
+        assert_eq!(counter.as_atomic_u32().load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn flags_and_signed() {
+        let flag = SuperCell::new(false);
+        let total = SuperCell::new(0isize);
+        thread::scope(|scope| {
+            scope.spawn(|| flag.as_atomic_bool().store(true, Ordering::Release));
+            scope.spawn(|| total.as_atomic_isize().fetch_sub(5, Ordering::Relaxed));
+        });
+        assert!(*flag.get());
+        assert_eq!(*total.get(), -5);
+    }
+}
@@ -0,0 +1,430 @@
+use core::fmt::{Debug, Formatter};
+use core::ops::{Deref, DerefMut};
+use crate::error::{BorrowError, BorrowMutError};
+use crate::SuperCell;
+
+/// Shared borrow of a `SuperCell`, returned by [`SuperCell::borrow`]
+///
+/// Without the `strict` feature this is a plain wrapper around the cell reference and checks
+/// nothing.
+#[must_use = "the borrow ends as soon as the guard is dropped"]
+pub struct CellRef<'a, T : ?Sized> {
+    cell : &'a SuperCell<T>
+}
+
+/// Mutable borrow of a `SuperCell`, returned by [`SuperCell::borrow_mut`]
+///
+/// Without the `strict` feature this is a plain wrapper around the cell reference and checks
+/// nothing.
+#[must_use = "the borrow ends as soon as the guard is dropped"]
+pub struct CellRefMut<'a, T : ?Sized> {
+    cell : &'a SuperCell<T>
+}
+
+impl<T : ?Sized> SuperCell<T> {
+    /// Borrows the value for reading
+    ///
+    /// # Panics
+    ///
+    /// With the `strict` feature, panics if the value is currently mutably borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let cell = SuperCell::new(vec![1, 2, 3]);
+    /// let first = cell.borrow();
+    /// let second = cell.borrow();
+    /// assert_eq!(first.len(), second.len());
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn borrow(&self) -> CellRef<'_, T> {
+        #[cfg(feature = "strict")]
+        strict::acquire_shared(self);
+        CellRef { cell : self }
+    }
+
+    /// Borrows the value for writing
+    ///
+    /// # Panics
+    ///
+    /// With the `strict` feature, panics if the value is currently borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let cell = SuperCell::new(vec![1, 2, 3]);
+    /// cell.borrow_mut().push(4);
+    /// assert_eq!(cell.borrow().len(), 4);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn borrow_mut(&self) -> CellRefMut<'_, T> {
+        #[cfg(feature = "strict")]
+        strict::acquire_exclusive(self);
+        crate::location::record_write(self);
+        CellRefMut { cell : self }
+    }
+
+    /// Borrows the value for reading, or returns an error instead of panicking if it is
+    /// currently mutably borrowed
+    ///
+    /// Never fails without the `strict` feature.
+    #[inline]
+    #[track_caller]
+    pub fn try_borrow(&self) -> Result<CellRef<'_, T>, BorrowError> {
+        #[cfg(feature = "strict")]
+        strict::try_shared(self, true)?;
+        Ok(CellRef { cell : self })
+    }
+
+    /// Borrows the value for writing, or returns an error instead of panicking if it is
+    /// currently borrowed
+    ///
+    /// Never fails without the `strict` feature.
+    #[inline]
+    #[track_caller]
+    pub fn try_borrow_mut(&self) -> Result<CellRefMut<'_, T>, BorrowMutError> {
+        #[cfg(feature = "strict")]
+        strict::try_exclusive(self, true)?;
+        crate::location::record_write(self);
+        Ok(CellRefMut { cell : self })
+    }
+
+    /// Returns a shared reference to the value, or an error if it is currently mutably borrowed
+    ///
+    /// With the `strict` feature the check is against the `borrow_mut` guards alive at the time
+    /// of the call. The reference itself is not tracked, so it does not stop later borrows, and
+    /// without `strict` this never fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let cell = SuperCell::new(1);
+    /// match cell.try_get() {
+    ///     Ok(value) => assert_eq!(*value, 1),
+    ///     Err(error) => panic!("{error}")
+    /// }
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn try_get(&self) -> Result<&T, BorrowError> {
+        #[cfg(feature = "strict")]
+        strict::try_shared(self, false)?;
+        Ok(self.get())
+    }
+
+    /// Returns a mutable reference to the value, or an error if it is currently borrowed
+    ///
+    /// Same as [`try_get`](SuperCell::try_get), checking against every guard alive at the time
+    /// of the call. With `strict` this is the checked alternative to the deprecated `get_mut`.
+    #[inline]
+    #[track_caller]
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_get_mut(&self) -> Result<&mut T, BorrowMutError> {
+        #[cfg(feature = "strict")]
+        strict::try_exclusive(self, false)?;
+        Ok(self.get_mut())
+    }
+}
+
+impl<T : ?Sized> Deref for CellRef<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.cell.get()
+    }
+}
+
+impl<T : ?Sized> Deref for CellRefMut<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.cell.get()
+    }
+}
+
+impl<T : ?Sized> DerefMut for CellRefMut<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: The guard is the only borrow of the value in use, `strict` checks this and
+        // the loose build leaves it to the caller like `get_mut` does.
+        unsafe { &mut *self.cell.as_ptr() }
+    }
+}
+
+impl<T : Debug + ?Sized> Debug for CellRef<'_, T> {
+    fn fmt(&self, f : &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<T : Debug + ?Sized> Debug for CellRefMut<'_, T> {
+    fn fmt(&self, f : &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(feature = "strict")]
+impl<T : ?Sized> Drop for CellRef<'_, T> {
+    fn drop(&mut self) {
+        strict::release_shared(self.cell);
+    }
+}
+
+#[cfg(feature = "strict")]
+impl<T : ?Sized> Drop for CellRefMut<'_, T> {
+    fn drop(&mut self) {
+        strict::release_exclusive(self.cell);
+    }
+}
+
+/// Borrow states for `strict` builds
+///
+/// The state lives in a side table instead of inside the cell, so `SuperCell<T>` keeps the layout
+/// of `T` and the slice and pointer conversions stay valid. Entries are keyed by the address,
+/// size and type of the value, so a cell nested at the start of another one, which shares its
+/// address, is tracked separately. A positive count tracks shared borrows and `-1` marks a
+/// mutable one, alongside the location of the latest borrow so conflicts can report both call
+/// sites. Zero sized values are not tracked, as they all share addresses and hold nothing to
+/// conflict over.
+///
+/// A guard passed to `mem::forget` never releases its borrow. The entry outlives the cell, so a
+/// value of the same type later placed at the same address starts out borrowed, and conflicts
+/// with it report the location of the forgotten borrow.
+#[cfg(feature = "strict")]
+mod strict {
+    use core::any::type_name;
+    use core::panic::Location;
+    use std::collections::BTreeMap;
+    use std::sync::{Mutex, PoisonError};
+    use crate::error::{AccessKind, BorrowError, BorrowMutError};
+    use crate::SuperCell;
+
+    struct State {
+        count : isize,
+        location : &'static Location<'static>
+    }
+
+    /// Address, size and type name of a value
+    type Key = (usize, usize, &'static str);
+
+    static STATES : Mutex<BTreeMap<Key, State>> = Mutex::new(BTreeMap::new());
+
+    fn key<T : ?Sized>(cell : &SuperCell<T>) -> Option<Key> {
+        cell.tracking_key().map(|address| (address, size_of_val(cell), type_name::<T>()))
+    }
+
+    /// Returns the kind and location of the borrow in the way, otherwise records the new borrow
+    /// if `register` is set
+    #[track_caller]
+    fn try_acquire<T : ?Sized>(cell : &SuperCell<T>, exclusive : bool, register : bool) -> Result<(), (AccessKind, &'static Location<'static>)> {
+        let Some(key) = key(cell) else {
+            return Ok(());
+        };
+        let location = Location::caller();
+        let mut states = STATES.lock().unwrap_or_else(PoisonError::into_inner);
+        let conflict = states.get(&key).and_then(|state| match state.count {
+            count if count < 0 => Some(AccessKind::Exclusive),
+            count if count > 0 && exclusive => Some(AccessKind::Shared),
+            _ => None
+        }.map(|kind| (kind, state.location)));
+        if let Some(conflict) = conflict {
+            return Err(conflict);
+        }
+        if register {
+            let state = states.entry(key).or_insert(State { count : 0, location });
+            state.count = if exclusive { -1 } else { state.count + 1 };
+            state.location = location;
+        }
+        Ok(())
+    }
+
+    #[track_caller]
+    fn acquire<T : ?Sized>(cell : &SuperCell<T>, exclusive : bool) {
+        if let Err((kind, previous)) = try_acquire(cell, exclusive, true) {
+            panic!("{}: borrowed at {previous}, conflicting borrow at {}", kind.conflict_message(), Location::caller());
+        }
+    }
+
+    fn release<T : ?Sized>(cell : &SuperCell<T>, exclusive : bool) {
+        let Some(key) = key(cell) else {
+            return;
+        };
+        let mut states = STATES.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(state) = states.get_mut(&key) {
+            state.count = if exclusive { 0 } else { state.count - 1 };
+            if state.count == 0 {
+                states.remove(&key);
+            }
+        }
+    }
+
+    #[track_caller]
+    pub(super) fn acquire_shared<T : ?Sized>(cell : &SuperCell<T>) {
+        acquire(cell, false);
+    }
+
+    #[track_caller]
+    pub(super) fn acquire_exclusive<T : ?Sized>(cell : &SuperCell<T>) {
+        acquire(cell, true);
+    }
+
+    #[track_caller]
+    pub(super) fn try_shared<T : ?Sized>(cell : &SuperCell<T>, register : bool) -> Result<(), BorrowError> {
+        try_acquire(cell, false, register).map_err(|(kind, location)| BorrowError::new(kind, location))
+    }
+
+    #[track_caller]
+    pub(super) fn try_exclusive<T : ?Sized>(cell : &SuperCell<T>, register : bool) -> Result<(), BorrowMutError> {
+        try_acquire(cell, true, register).map_err(|(kind, location)| BorrowMutError::new(kind, location))
+    }
+
+    pub(super) fn release_shared<T : ?Sized>(cell : &SuperCell<T>) {
+        release(cell, false);
+    }
+
+    pub(super) fn release_exclusive<T : ?Sized>(cell : &SuperCell<T>) {
+        release(cell, true);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use super::*;
+
+    #[test]
+    fn sequential_borrows() {
+        let cell = SuperCell::new(vec![1, 2]);
+        cell.borrow_mut().push(3);
+        let first = cell.borrow();
+        let second = cell.borrow();
+        assert_eq!(*first, *second);
+        drop((first, second));
+        cell.borrow_mut().push(4);
+        assert_eq!(*cell.borrow(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "strict", should_panic(expected = "already borrowed"))]
+    fn overlapping_mutable_borrow() {
+        let cell = SuperCell::new(1);
+        let shared = cell.borrow();
+        assert_eq!(*shared, 1);
+        let mut exclusive = cell.borrow_mut();
+        *exclusive += 1;
+        drop(exclusive);
+        assert_eq!(*shared, 2);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "strict", should_panic(expected = "already mutably borrowed"))]
+    fn overlapping_shared_borrow() {
+        let cell = SuperCell::new(1);
+        let mut exclusive = cell.borrow_mut();
+        *exclusive += 1;
+        assert_eq!(*cell.borrow(), 2);
+    }
+
+    #[cfg(feature = "strict")]
+    #[test]
+    fn conflict_reports_both_locations() {
+        let cell = SuperCell::new(1);
+        let first = line!() + 1;
+        let _shared = cell.borrow();
+        let second = line!() + 1;
+        let payload = catch_unwind(AssertUnwindSafe(|| cell.borrow_mut())).expect_err("borrow should conflict");
+        let message = payload.downcast_ref::<String>().expect("message should be formatted");
+        assert!(message.contains(&format!("{}:{first}:", file!())));
+        assert!(message.contains(&format!("{}:{second}:", file!())));
+    }
+
+    #[test]
+    fn released_after_unwind() {
+        let cell = SuperCell::new(1);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let _exclusive = cell.borrow_mut();
+            panic!("Unwinding with a borrow held");
+        }));
+        assert!(result.is_err());
+        *cell.borrow_mut() = 2;
+        assert_eq!(*cell.borrow(), 2);
+    }
+
+    #[test]
+    fn nested_at_same_address() {
+        struct Outer {
+            inner : SuperCell<u32>,
+            slot : SuperCell<u32>
+        }
+        let outer = SuperCell::new(Outer { inner : SuperCell::new(1), slot : SuperCell::new(0) });
+        outer.with(|outer| {
+            let _inner = outer.inner.borrow_mut();
+            outer.slot.set(2);
+        });
+        let wrapper = SuperCell::new(SuperCell::new(3u32));
+        wrapper.with(|inner| *inner.borrow_mut() += 1);
+        assert_eq!(*wrapper.get().get(), 4);
+    }
+
+    #[test]
+    fn zero_sized_values() {
+        let cell = SuperCell::new(());
+        let _exclusive = cell.borrow_mut();
+        let other = SuperCell::new(());
+        let _second = other.borrow_mut();
+    }
+
+    #[cfg(feature = "strict")]
+    #[test]
+    fn fallible_accessors_check() {
+        use crate::error::AccessKind;
+        let cell = SuperCell::new(1);
+        let shared = cell.try_borrow().unwrap();
+        assert_eq!(*cell.try_get().unwrap(), 1);
+        let error = cell.try_get_mut().unwrap_err();
+        assert_eq!(error.conflicting(), AccessKind::Shared);
+        assert!(cell.try_borrow_mut().is_err());
+        drop(shared);
+
+        let line = line!() + 1;
+        let exclusive = cell.try_borrow_mut().unwrap();
+        let error = cell.try_get().unwrap_err();
+        assert_eq!(error.conflicting(), AccessKind::Exclusive);
+        assert!(cell.try_borrow().is_err());
+        assert!(error.to_string().starts_with("already mutably borrowed"));
+        if cfg!(debug_assertions) {
+            let location = format!("{}:{line}:", file!());
+            assert!(error.to_string().contains(&location), "{error}");
+            assert!(error.location().unwrap().to_string().starts_with(&location));
+        } else {
+            assert!(error.location().is_none());
+        }
+        drop(exclusive);
+        *cell.try_get_mut().unwrap() += 1;
+        assert_eq!(*cell.try_borrow().unwrap(), 2);
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn fallible_accessors_succeed() {
+        let cell = SuperCell::new(1);
+        let _exclusive = cell.try_borrow_mut().unwrap();
+        *cell.try_get_mut().unwrap() += 1;
+        assert_eq!(*cell.try_get().unwrap(), 2);
+        assert!(cell.try_borrow().is_ok());
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn loose_guards_are_references() {
+        assert_eq!(size_of::<CellRef<'_, u64>>(), size_of::<&u64>());
+        assert_eq!(size_of::<CellRefMut<'_, [u64]>>(), size_of::<&[u64]>());
+    }
+}
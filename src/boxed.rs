@@ -0,0 +1,72 @@
+use alloc::boxed::Box;
+use crate::SuperCell;
+
+/// Helpers for optional boxed payload slots
+impl<T> SuperCell<Option<Box<T>>> {
+    /// Takes the box out of the slot, leaving it empty
+    #[inline]
+    pub fn take_boxed(&self) -> Option<Box<T>> {
+        self.get_mut().take()
+    }
+
+    /// Boxes `value` and stores it, returning the box it displaced
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let slot = SuperCell::new(None);
+    /// assert_eq!(slot.set_boxed(1), None);
+    /// assert_eq!(slot.set_boxed(2), Some(Box::new(1)));
+    /// assert_eq!(slot.get_deref(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn set_boxed(&self, value : T) -> Option<Box<T>> {
+        self.replace(Some(Box::new(value)))
+    }
+
+    /// Returns a reference to the boxed value, if there is one
+    #[inline]
+    pub fn get_deref(&self) -> Option<&T> {
+        self.get().as_deref()
+    }
+
+    /// Returns a mutable reference to the boxed value, boxing the result of `f` first if the
+    /// slot is empty
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub fn get_or_insert_boxed_with(&self, f : impl FnOnce() -> T) -> &mut T {
+        self.get_mut().get_or_insert_with(|| Box::new(f()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+    use super::*;
+
+    #[test]
+    fn lifecycle() {
+        let slot : SuperCell<Option<Box<Vec<u32>>>> = SuperCell::new(None);
+        assert_eq!(slot.get_deref(), None);
+        slot.get_or_insert_boxed_with(Vec::new).push(1);
+        slot.get_or_insert_boxed_with(|| unreachable!()).push(2);
+        assert_eq!(slot.get_deref(), Some(&vec![1, 2]));
+        assert_eq!(slot.set_boxed(vec![3]), Some(Box::new(vec![1, 2])));
+        assert_eq!(slot.take_boxed(), Some(Box::new(vec![3])));
+        assert_eq!(slot.take_boxed(), None);
+    }
+
+    #[test]
+    fn displaced_boxes_are_returned() {
+        let payload = Rc::new(());
+        let slot = SuperCell::new(None);
+        slot.set_boxed(Rc::clone(&payload));
+        let displaced = slot.set_boxed(Rc::clone(&payload));
+        assert_eq!(Rc::strong_count(&payload), 3);
+        drop(displaced);
+        assert_eq!(Rc::strong_count(&payload), 2);
+        drop(slot);
+        assert_eq!(Rc::strong_count(&payload), 1);
+    }
+}
@@ -0,0 +1,222 @@
+use core::cell::UnsafeCell;
+use core::marker::PhantomData;
+use crate::SuperCell;
+
+/// Invariant lifetime marker, so one brand can never be coerced into another
+type Brand<'brand> = PhantomData<fn(&'brand ()) -> &'brand ()>;
+
+/// Token proving access to every [`BrandedCell`] of the same brand
+///
+/// Only [`CellOwner::scope`] creates owners, each with a fresh brand that no other owner shares.
+/// Shared access to the owner grants shared access to the cells, and exclusive access grants
+/// exclusive access, so the borrow checker enforces the aliasing rules of `SuperCell`.
+pub struct CellOwner<'brand> {
+    _brand : Brand<'brand>
+}
+
+/// Cell with the same sharing model as `SuperCell`, with accesses checked at compile time
+/// through a [`CellOwner`]
+///
+/// # Examples
+///
+/// ```
+/// use super_cell::{BrandedCell, CellOwner};
+/// CellOwner::scope(|mut owner| {
+///     let cells = [BrandedCell::new(1), BrandedCell::new(2)];
+///     let first = &cells[0];
+///     *first.get_mut(&mut owner) += 10;
+///     assert_eq!(*cells[0].get(&owner), 11);
+/// });
+/// ```
+///
+/// A cell cannot be used with the owner of another scope:
+///
+/// ```compile_fail
+/// use super_cell::{BrandedCell, CellOwner};
+/// CellOwner::scope(|owner| {
+///     let cell = BrandedCell::new(1);
+///     CellOwner::scope(|other| {
+///         cell.get(&other);
+///     });
+///     drop(owner);
+/// });
+/// ```
+///
+/// Nor can two mutable references be live at once without [`CellOwner::get_mut2`]:
+///
+/// ```compile_fail
+/// use super_cell::{BrandedCell, CellOwner};
+/// CellOwner::scope(|mut owner| {
+///     let (a, b) = (BrandedCell::new(1), BrandedCell::new(2));
+///     let a = a.get_mut(&mut owner);
+///     let b = b.get_mut(&mut owner);
+///     *a += *b;
+/// });
+/// ```
+#[repr(transparent)]
+pub struct BrandedCell<'brand, T : ?Sized> {
+    _brand : Brand<'brand>,
+    value : UnsafeCell<T>
+}
+
+// SAFETY: Shared access to the value needs a `&CellOwner`, and exclusive access a
+// `&mut CellOwner`, which can be on any thread.
+unsafe impl<T : ?Sized + Send + Sync> Sync for BrandedCell<'_, T> {}
+
+impl CellOwner<'_> {
+    /// Runs `f` with a new owner, whose brand cannot escape the closure
+    pub fn scope<R>(f : impl for<'new> FnOnce(CellOwner<'new>) -> R) -> R {
+        f(CellOwner { _brand : PhantomData })
+    }
+}
+
+impl<'brand> CellOwner<'brand> {
+    /// Returns mutable references to two cells at once
+    ///
+    /// # Panics
+    ///
+    /// Panics if the values of the cells overlap.
+    #[track_caller]
+    pub fn get_mut2<'a, T, U>(&'a mut self, first : &'a BrandedCell<'brand, T>, second : &'a BrandedCell<'brand, U>) -> (&'a mut T, &'a mut U) {
+        let (first, second) = (first.value.get(), second.value.get());
+        let (start, other) = (first as usize, second as usize);
+        let overlap = start < other + size_of::<U>() && other < start + size_of::<T>();
+        assert!(!overlap, "the cells overlap");
+        // SAFETY: The owner is borrowed mutably for `'a`, and the values are disjoint.
+        unsafe { (&mut *first, &mut *second) }
+    }
+}
+
+impl<'brand, T> BrandedCell<'brand, T> {
+    #[inline]
+    pub const fn new(value : T) -> Self {
+        Self {
+            _brand : PhantomData,
+            value : UnsafeCell::new(value)
+        }
+    }
+
+    /// Converts a `SuperCell` into a branded cell holding the same value
+    #[inline]
+    pub fn from_cell(cell : SuperCell<T>) -> Self {
+        Self::new(cell.into_inner())
+    }
+
+    /// Converts the branded cell back into a `SuperCell`
+    #[inline]
+    pub fn into_cell(self) -> SuperCell<T> {
+        SuperCell::new(self.into_inner())
+    }
+
+    /// Consumes the cell, returning the wrapped value
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<'brand, T : ?Sized> BrandedCell<'brand, T> {
+    /// Returns a shared reference to the value, which lives as long as the owner borrow
+    #[inline]
+    pub fn get<'a>(&'a self, _owner : &'a CellOwner<'brand>) -> &'a T {
+        // SAFETY: Mutable references need the owner borrowed mutably, which it is not for `'a`.
+        unsafe { &*self.value.get() }
+    }
+
+    /// Returns a mutable reference to the value, which keeps the owner borrowed mutably
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub fn get_mut<'a>(&'a self, _owner : &'a mut CellOwner<'brand>) -> &'a mut T {
+        // SAFETY: The owner is borrowed mutably for `'a`, so no other reference to a cell of this
+        // brand can be created in the meantime.
+        unsafe { &mut *self.value.get() }
+    }
+
+    /// Returns a raw pointer to the underlying data in this cell
+    #[inline]
+    pub const fn as_ptr(&self) -> *mut T {
+        self.value.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::swap;
+    use super::*;
+
+    type Link<'arena, 'brand> = Option<&'arena BrandedCell<'brand, Node<'arena, 'brand>>>;
+
+    struct Node<'arena, 'brand> {
+        value : u32,
+        prev : Link<'arena, 'brand>,
+        next : Link<'arena, 'brand>
+    }
+
+    fn node<'arena, 'brand>(value : u32) -> BrandedCell<'brand, Node<'arena, 'brand>> {
+        BrandedCell::new(Node { value, prev : None, next : None })
+    }
+
+    fn values<'brand>(head : &BrandedCell<'brand, Node<'_, 'brand>>, owner : &CellOwner<'brand>) -> Vec<u32> {
+        let mut values = Vec::new();
+        let mut current = Some(head);
+        while let Some(node) = current {
+            values.push(node.get(owner).value);
+            current = node.get(owner).next;
+        }
+        values
+    }
+
+    #[test]
+    fn doubly_linked_list() {
+        CellOwner::scope(|mut owner| {
+            let nodes = [node(1), node(2), node(3)];
+            for pair in nodes.windows(2) {
+                let (left, right) = owner.get_mut2(&pair[0], &pair[1]);
+                left.next = Some(&pair[1]);
+                right.prev = Some(&pair[0]);
+            }
+            assert_eq!(values(&nodes[0], &owner), [1, 2, 3]);
+
+            let middle = nodes[0].get(&owner).next.unwrap();
+            let last = middle.get(&owner).next.unwrap();
+            let (middle, last) = owner.get_mut2(middle, last);
+            swap(&mut middle.value, &mut last.value);
+            assert_eq!(values(&nodes[0], &owner), [1, 3, 2]);
+
+            let before = nodes[2].get(&owner).prev.unwrap();
+            before.get_mut(&mut owner).value += 10;
+            assert_eq!(nodes[1].get(&owner).value, 13);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "the cells overlap")]
+    fn same_cell_twice() {
+        CellOwner::scope(|mut owner| {
+            let cell = BrandedCell::new(1);
+            owner.get_mut2(&cell, &cell);
+        });
+    }
+
+    #[test]
+    fn converts_from_and_to_super_cell() {
+        let cell = SuperCell::new(vec![1]);
+        let cell = CellOwner::scope(|mut owner| {
+            let branded = BrandedCell::from_cell(cell);
+            branded.get_mut(&mut owner).push(2);
+            branded.into_cell()
+        });
+        assert_eq!(cell.into_inner(), [1, 2]);
+    }
+
+    #[test]
+    fn shared_across_threads() {
+        CellOwner::scope(|mut owner| {
+            let cell = BrandedCell::new(0);
+            std::thread::scope(|scope| {
+                scope.spawn(|| *cell.get_mut(&mut owner) += 1);
+            });
+            assert_eq!(*cell.get(&owner), 1);
+        });
+    }
+}
@@ -0,0 +1,203 @@
+use alloc::vec::Vec;
+use bytes::buf::UninitSlice;
+use bytes::{Buf, BufMut};
+use crate::SuperCell;
+
+/// Adapter returned by [`SuperCell::buf`] implementing `bytes::Buf` over the bytes in a cell
+///
+/// The adapter keeps its own cursor, so several of them over the same cell are independent of
+/// each other. Bytes appended to a `Vec` after the adapter was created are read as well.
+pub struct CellBuf<'a, B : ?Sized> {
+    cell : &'a SuperCell<B>,
+    position : usize
+}
+
+/// Adapter returned by [`SuperCell::buf_mut`] implementing `bytes::BufMut` by appending to a
+/// `SuperCell<Vec<u8>>`, growing it as needed
+#[derive(Clone, Copy)]
+pub struct CellBufMut<'a> {
+    cell : &'a SuperCell<Vec<u8>>
+}
+
+impl SuperCell<Vec<u8>> {
+    /// Returns an adapter implementing `bytes::BufMut` which appends to the bytes in this cell
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::{Buf, BufMut};
+    /// use super_cell::SuperCell;
+    /// let packet = SuperCell::new(vec![]);
+    /// packet.buf_mut().put_u16(0xCAFE);
+    /// packet.buf_mut().put_slice(b"hi");
+    /// let mut buf = packet.buf();
+    /// assert_eq!(buf.get_u16(), 0xCAFE);
+    /// assert_eq!(buf.chunk(), b"hi");
+    /// ```
+    #[inline]
+    pub fn buf_mut(&self) -> CellBufMut<'_> {
+        CellBufMut { cell : self }
+    }
+
+    /// Returns an adapter implementing `bytes::Buf` over the bytes in this cell
+    #[inline]
+    pub fn buf(&self) -> CellBuf<'_, Vec<u8>> {
+        CellBuf { cell : self, position : 0 }
+    }
+}
+
+impl SuperCell<[u8]> {
+    /// Returns an adapter implementing `bytes::Buf` over the bytes in this cell
+    #[inline]
+    pub fn buf(&self) -> CellBuf<'_, [u8]> {
+        CellBuf { cell : self, position : 0 }
+    }
+}
+
+impl<B : ?Sized> Clone for CellBuf<'_, B> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<B : ?Sized> Copy for CellBuf<'_, B> {}
+
+impl<B : AsRef<[u8]> + ?Sized> CellBuf<'_, B> {
+    /// Returns the number of bytes read so far
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl<B : AsRef<[u8]> + ?Sized> Buf for CellBuf<'_, B> {
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.chunk().len()
+    }
+
+    #[inline]
+    fn chunk(&self) -> &[u8] {
+        let bytes = self.cell.get().as_ref();
+        // A `Vec` shrunk under the cursor has nothing left to read.
+        &bytes[self.position.min(bytes.len())..]
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `count` is greater than `remaining`, like for `&[u8]`.
+    #[inline]
+    fn advance(&mut self, count : usize) {
+        self.chunk().advance(count);
+        self.position += count;
+    }
+}
+
+// SAFETY: Every method forwards to the `BufMut` implementation of the `Vec`, which upholds the
+// contract.
+unsafe impl BufMut for CellBufMut<'_> {
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        self.cell.get().remaining_mut()
+    }
+
+    #[inline]
+    unsafe fn advance_mut(&mut self, count : usize) {
+        // SAFETY: The caller upholds the contract of `advance_mut`.
+        unsafe { self.cell.get_mut().advance_mut(count) }
+    }
+
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        self.cell.get_mut().chunk_mut()
+    }
+
+    #[inline]
+    fn put_slice(&mut self, src : &[u8]) {
+        self.cell.get_mut().extend_from_slice(src)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use super::*;
+
+    /// Writes a frame of a big endian kind, a little endian length and the payload
+    fn encode(mut buf : impl BufMut, kind : u16, payload : &[u8]) {
+        buf.put_u16(kind);
+        buf.put_u32_le(payload.len() as u32);
+        buf.put_slice(payload);
+    }
+
+    fn decode(buf : &mut impl Buf) -> (u16, Bytes) {
+        let kind = buf.get_u16();
+        let len = buf.get_u32_le() as usize;
+        (kind, buf.copy_to_bytes(len))
+    }
+
+    #[test]
+    fn framed_round_trip() {
+        let stream = SuperCell::new(Vec::new());
+        encode(stream.buf_mut(), 1, b"hello");
+        encode(stream.buf_mut(), 2, &[0xAB; 300]);
+        assert_eq!(stream.len(), 2 * 6 + 5 + 300);
+
+        let mut buf = stream.buf();
+        assert_eq!(decode(&mut buf), (1, Bytes::from_static(b"hello")));
+        assert_eq!(buf.position(), 11);
+        // Frames appended while reading are picked up by the same cursor.
+        encode(stream.buf_mut(), 3, b"");
+        assert_eq!(decode(&mut buf), (2, Bytes::from(vec![0xAB; 300])));
+        assert_eq!(decode(&mut buf), (3, Bytes::new()));
+        assert!(!buf.has_remaining());
+
+        let mut copy = Bytes::copy_from_slice(stream.get());
+        assert_eq!(decode(&mut copy).1, "hello");
+        let echo = SuperCell::new(Vec::new());
+        echo.buf_mut().put(copy);
+        let mut buf = echo.buf();
+        assert_eq!(decode(&mut buf).0, 2);
+        assert_eq!(decode(&mut buf).0, 3);
+    }
+
+    #[test]
+    fn slice_cursor() {
+        let mut bytes = *b"\x00\x2Aabc";
+        let cell = SuperCell::from_mut_slice(&mut bytes);
+        let mut buf = cell.buf();
+        assert_eq!(buf.get_u16(), 42);
+        let other = cell.buf();
+        assert_eq!(other.remaining(), 5);
+        assert_eq!(buf.chunk(), b"abc");
+        cell.as_slice_of_cells()[2].set(b'x');
+        assert_eq!(buf.copy_to_bytes(3), "xbc");
+    }
+
+    #[test]
+    fn copied_cursors_are_independent() {
+        let stream = SuperCell::new(b"\x00\x01\x00\x02".to_vec());
+        let mut first = stream.buf();
+        let mut second = first;
+        assert_eq!(first.get_u16(), 1);
+        assert_eq!(second.get_u16(), 1);
+        assert_eq!(second.get_u16(), 2);
+        assert_eq!((first.position(), second.position()), (2, 4));
+
+        let mut bytes = *b"ab";
+        let cell = SuperCell::from_mut_slice(&mut bytes);
+        let mut buf = cell.buf();
+        let copy = buf;
+        buf.advance(1);
+        assert_eq!((buf.chunk(), copy.chunk()), (&b"b"[..], &b"ab"[..]));
+    }
+
+    #[test]
+    #[should_panic(expected = "advance out of bounds")]
+    fn over_advance() {
+        let cell = SuperCell::new(vec![1, 2, 3]);
+        let mut buf = cell.buf();
+        buf.advance(2);
+        buf.advance(2);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::SuperCell;
+
+/// Helpers for invoking closures stored in a cell
+///
+/// With the nightly `fn_traits` feature, `SuperCell<F>` also implements the `Fn` traits,
+/// so it can be called directly like `cell(event)` and passed by reference wherever an
+/// `impl Fn` is expected.
+impl<F> SuperCell<F> {
+    /// Calls `f` with a mutable reference to the stored closure
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let callback : SuperCell<Box<dyn FnMut(u32) -> u32>> = SuperCell::new(Box::new(|x| x + 1));
+    /// assert_eq!(callback.invoke(|f| f(1)), 2);
+    /// ```
+    #[inline]
+    pub fn invoke<R>(&self, f : impl FnOnce(&mut F) -> R) -> R {
+        f(self.get_mut())
+    }
+
+    /// Calls the stored closure without arguments
+    #[inline]
+    pub fn call0<R>(&self) -> R where F : FnMut() -> R {
+        (self.get_mut())()
+    }
+
+    /// Calls the stored closure with one argument
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let callback : SuperCell<Box<dyn FnMut(&str) -> usize>> = SuperCell::new(Box::new(|event| event.len()));
+    /// assert_eq!(callback.call1("click"), 5);
+    /// callback.set(Box::new(|_| 0));
+    /// assert_eq!(callback.call1("click"), 0);
+    /// ```
+    #[inline]
+    pub fn call1<A, R>(&self, a : A) -> R where F : FnMut(A) -> R {
+        (self.get_mut())(a)
+    }
+
+    /// Calls the stored closure with two arguments
+    #[inline]
+    pub fn call2<A, B, R>(&self, a : A, b : B) -> R where F : FnMut(A, B) -> R {
+        (self.get_mut())(a, b)
+    }
+}
+
+#[cfg(feature = "fn_traits")]
+impl<Args : core::marker::Tuple, F : FnMut<Args>> FnOnce<Args> for SuperCell<F> {
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args : Args) -> F::Output {
+        self.into_inner().call_mut(args)
+    }
+}
+
+#[cfg(feature = "fn_traits")]
+impl<Args : core::marker::Tuple, F : FnMut<Args>> FnMut<Args> for SuperCell<F> {
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args : Args) -> F::Output {
+        self.value.get_mut().call_mut(args)
+    }
+}
+
+#[cfg(feature = "fn_traits")]
+impl<Args : core::marker::Tuple, F : FnMut<Args>> Fn<Args> for SuperCell<F> {
+    #[inline]
+    extern "rust-call" fn call(&self, args : Args) -> F::Output {
+        self.get_mut().call_mut(args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+    use std::cell::Cell;
+    use super::*;
+
+    type Callback = Box<dyn FnMut(u32)>;
+
+    #[test]
+    fn stored_callbacks() {
+        let total = Rc::new(Cell::new(0));
+        let counter = Rc::clone(&total);
+        let callback : SuperCell<Callback> = SuperCell::new(Box::new(move |event| counter.set(counter.get() + event)));
+        for event in 1..=3 {
+            callback.call1(event);
+        }
+        assert_eq!(total.get(), 6);
+
+        let counter = Rc::clone(&total);
+        let previous = callback.replace(Box::new(move |event| counter.set(counter.get() * event)));
+        drop(previous);
+        callback.call1(10);
+        callback.invoke(|f| f(2));
+        assert_eq!(total.get(), 120);
+    }
+
+    #[test]
+    fn arities() {
+        let mut calls = 0;
+        let zero = SuperCell::new(|| {
+            calls += 1;
+            calls
+        });
+        assert_eq!(zero.call0(), 1);
+        assert_eq!(zero.call0(), 2);
+        let two = SuperCell::new(|a : u32, b : u32| a * b);
+        assert_eq!(two.call2(3, 4), 12);
+    }
+
+    #[cfg(feature = "fn_traits")]
+    #[test]
+    fn fn_traits() {
+        let mut total = 0;
+        let callback = SuperCell::new(|event : u32| {
+            total += event;
+            total
+        });
+        assert_eq!(callback(1), 1);
+        assert_eq!(callback(2), 3);
+        let results : Vec<u32> = (1..=2).map(&callback).collect();
+        assert_eq!(results, vec![4, 6]);
+    }
+}
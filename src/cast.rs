@@ -0,0 +1,140 @@
+use core::marker::PhantomData;
+use core::mem::{align_of, size_of};
+use crate::SuperCell;
+
+/// Evaluated at monomorphization time, so mismatched layouts fail the build instead of a check
+/// at runtime
+struct SameLayout<T, U>(PhantomData<(T, U)>);
+
+impl<T, U> SameLayout<T, U> {
+    const ASSERT : () = {
+        assert!(size_of::<T>() == size_of::<U>(), "cast between types of different sizes");
+        assert!(align_of::<T>() == align_of::<U>(), "cast between types of different alignments");
+    };
+}
+
+impl<T> SuperCell<T> {
+    /// Reinterprets the cell as a cell of `U`
+    ///
+    /// Sizes and alignments are checked at compile time.
+    ///
+    /// # Safety
+    ///
+    /// Every value of `T` must be a valid `U` and every value of `U` a valid `T`, as writes
+    /// through either cell are seen by the other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// #[repr(transparent)]
+    /// struct Meters(f64);
+    /// let distance = SuperCell::new(1.5f64);
+    /// // SAFETY: `Meters` is a transparent wrapper around `f64`.
+    /// let meters = unsafe { distance.cast::<Meters>() };
+    /// meters.get_mut().0 += 1.0;
+    /// assert_eq!(*distance.get(), 2.5);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use super_cell::SuperCell;
+    /// let value = SuperCell::new(0u32);
+    /// let _ = unsafe { value.cast::<u64>() };
+    /// ```
+    #[inline]
+    pub unsafe fn cast<U>(&self) -> &SuperCell<U> {
+        let () = SameLayout::<T, U>::ASSERT;
+        // SAFETY: The layouts match, validity is up to the caller.
+        unsafe { &*(self as *const SuperCell<T>).cast::<SuperCell<U>>() }
+    }
+
+    /// Reinterprets the cell as a cell of a transparent wrapper around `T`
+    #[cfg(feature = "bytemuck")]
+    #[inline]
+    pub fn cast_wrapper<U : bytemuck::TransparentWrapper<T>>(&self) -> &SuperCell<U> {
+        // SAFETY: `TransparentWrapper` guarantees `U` is `repr(transparent)` over `T`.
+        unsafe { self.cast() }
+    }
+}
+
+impl<T> SuperCell<[T]> {
+    /// Reinterprets the cell as a cell of a slice of `U`, keeping the length
+    ///
+    /// Element sizes and alignments are checked at compile time.
+    ///
+    /// # Safety
+    ///
+    /// Every value of `T` must be a valid `U` and every value of `U` a valid `T`, as writes
+    /// through either cell are seen by the other.
+    ///
+    /// ```compile_fail
+    /// use super_cell::SuperCell;
+    /// let mut values = [0u8; 4];
+    /// let cell = SuperCell::from_mut_slice(&mut values);
+    /// let _ = unsafe { cell.cast_slice::<u16>() };
+    /// ```
+    #[inline]
+    pub unsafe fn cast_slice<U>(&self) -> &SuperCell<[U]> {
+        let () = SameLayout::<T, U>::ASSERT;
+        let ptr = core::ptr::slice_from_raw_parts(self.as_ptr().cast::<U>(), self.as_ptr().len());
+        // SAFETY: The element layouts match so the same length covers the same bytes, validity
+        // is up to the caller.
+        unsafe { &*(ptr as *const SuperCell<[U]>) }
+    }
+
+    /// Reinterprets the cell as a cell of a slice of transparent wrappers around `T`
+    #[cfg(feature = "bytemuck")]
+    #[inline]
+    pub fn cast_wrapper_slice<U : bytemuck::TransparentWrapper<T>>(&self) -> &SuperCell<[U]> {
+        // SAFETY: `TransparentWrapper` guarantees `U` is `repr(transparent)` over `T`.
+        unsafe { self.cast_slice() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    #[repr(transparent)]
+    struct Meters(f64);
+
+    #[cfg(feature = "bytemuck")]
+    // SAFETY: `Meters` is `repr(transparent)` over `f64`.
+    unsafe impl bytemuck::TransparentWrapper<f64> for Meters {}
+
+    #[test]
+    fn newtype() {
+        let distance = SuperCell::new(3.0f64);
+        // SAFETY: `Meters` is `repr(transparent)` over `f64`.
+        let meters = unsafe { distance.cast::<Meters>() };
+        assert_eq!(*meters.get(), Meters(3.0));
+        meters.set(Meters(4.0));
+        assert_eq!(*distance.get(), 4.0);
+    }
+
+    #[test]
+    fn slices() {
+        let mut values = [1.0f64, 2.0, 3.0];
+        let cell = SuperCell::from_mut_slice(&mut values);
+        // SAFETY: `Meters` is `repr(transparent)` over `f64`.
+        let meters = unsafe { cell.cast_slice::<Meters>() };
+        assert_eq!(meters.get().len(), 3);
+        meters.as_slice_of_cells()[2].get_mut().0 *= 10.0;
+        // SAFETY: Every bit pattern is a valid `u64` and `f64`.
+        let bits = unsafe { cell.cast_slice::<u64>() };
+        assert_eq!(bits.get()[0], 1.0f64.to_bits());
+        assert_eq!(values, [1.0, 2.0, 30.0]);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn wrappers() {
+        let distance = SuperCell::new(1.0f64);
+        distance.cast_wrapper::<Meters>().get_mut().0 = 2.0;
+        assert_eq!(*distance.get(), 2.0);
+        let mut values = [1.0f64, 2.0];
+        let cell = SuperCell::from_mut_slice(&mut values);
+        assert_eq!(cell.cast_wrapper_slice::<Meters>().get(), [Meters(1.0), Meters(2.0)]);
+    }
+}
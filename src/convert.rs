@@ -0,0 +1,396 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::mem::ManuallyDrop;
+use crate::SuperCell;
+
+impl<T> SuperCell<T> {
+    /// Converts a `Vec<T>` into a `Vec<SuperCell<T>>` without copying or reallocating
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let cells = SuperCell::wrap_vec(vec![1, 2, 3]);
+    /// *cells[1].get_mut() = 5;
+    /// assert_eq!(SuperCell::unwrap_vec(cells), vec![1, 5, 3]);
+    /// ```
+    pub fn wrap_vec(vec : Vec<T>) -> Vec<SuperCell<T>> {
+        let mut vec = ManuallyDrop::new(vec);
+        // SAFETY: `SuperCell<T>` has the same memory layout as `T`, so the allocation is reused as is.
+        unsafe { Vec::from_raw_parts(vec.as_mut_ptr().cast::<SuperCell<T>>(), vec.len(), vec.capacity()) }
+    }
+
+    /// Converts a `Vec<SuperCell<T>>` back into a `Vec<T>` without copying or reallocating
+    pub fn unwrap_vec(vec : Vec<SuperCell<T>>) -> Vec<T> {
+        let mut vec = ManuallyDrop::new(vec);
+        // SAFETY: `SuperCell<T>` has the same memory layout as `T`, so the allocation is reused as is.
+        unsafe { Vec::from_raw_parts(vec.as_mut_ptr().cast::<T>(), vec.len(), vec.capacity()) }
+    }
+
+    /// Returns a `&SuperCell<[T]>` from a `&mut [T]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let mut buffer = vec![0; 6];
+    /// let cell = SuperCell::from_mut_slice(buffer.as_mut_slice());
+    /// for (index, chunk) in cell.as_slice_of_cells().chunks(2).enumerate() {
+    ///     for value in chunk {
+    ///         *value.get_mut() = index;
+    ///     }
+    /// }
+    /// assert_eq!(buffer, vec![0, 0, 1, 1, 2, 2]);
+    /// ```
+    #[inline]
+    pub fn from_mut_slice(slice : &mut [T]) -> &SuperCell<[T]> {
+        SuperCell::from_mut(slice)
+    }
+
+    /// Returns a `&SuperCell<[T; N]>` from a `&mut [T; N]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let mut array = [1, 2, 3];
+    /// let cell = SuperCell::from_mut_array(&mut array);
+    /// *cell.as_array_of_cells()[0].get_mut() = 4;
+    /// assert_eq!(array, [4, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn from_mut_array<const N : usize>(array : &mut [T; N]) -> &SuperCell<[T; N]> {
+        SuperCell::from_mut(array)
+    }
+
+    /// Returns a `&SuperCell<[T]>` from a `&[SuperCell<T>]`, the inverse of
+    /// [`SuperCell::as_slice_of_cells`]
+    #[inline]
+    pub fn from_slice_of_cells(slice : &[SuperCell<T>]) -> &SuperCell<[T]> {
+        // SAFETY: `SuperCell<T>` has the same memory layout as `T`, and so does `SuperCell<[T]>` as `[T]`.
+        unsafe { &*(slice as *const [SuperCell<T>] as *const SuperCell<[T]>) }
+    }
+
+    /// Views a `&mut [T]` as a slice of cells
+    pub fn wrap_slice(slice : &mut [T]) -> &[SuperCell<T>] {
+        SuperCell::from_mut(slice).as_slice_of_cells()
+    }
+
+    /// Views a `&mut [SuperCell<T>]` as a plain mutable slice
+    pub fn peel_slice(slice : &mut [SuperCell<T>]) -> &mut [T] {
+        // SAFETY: `SuperCell<T>` has the same memory layout as `T` and `&mut` guarantees unique access.
+        unsafe { &mut *(slice as *mut [SuperCell<T>] as *mut [T]) }
+    }
+
+    /// Returns a `&SuperCell<T>` from a raw pointer, such as one handed out by C code
+    ///
+    /// # Safety
+    ///
+    /// - `ptr` must be non-null, properly aligned and point to an initialized `T`.
+    /// - The `T` must stay valid and must not be moved or freed for the whole lifetime `'a`.
+    /// - No Rust `&T` or `&mut T` to the same value may be used while the returned cell is
+    ///   alive. Accesses from C are allowed, as long as they do not race with accesses on the
+    ///   Rust side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let raw = Box::into_raw(Box::new(1u32));
+    /// // SAFETY: `raw` is valid until it is freed below.
+    /// let cell = unsafe { SuperCell::from_ptr(raw) };
+    /// cell.set(2);
+    /// assert_eq!(unsafe { *Box::from_raw(raw) }, 2);
+    /// ```
+    #[inline]
+    pub unsafe fn from_ptr<'a>(ptr : *mut T) -> &'a SuperCell<T> {
+        // SAFETY: The caller upholds the contract above and the layout is the same.
+        unsafe { &*ptr.cast::<SuperCell<T>>() }
+    }
+
+    /// Returns a `&SuperCell<[T]>` from a raw pointer and a length
+    ///
+    /// # Safety
+    ///
+    /// Same as [`SuperCell::from_ptr`], for all of the `len` consecutive elements starting at
+    /// `ptr`. `ptr` must be non-null and aligned even if `len` is 0, and the whole range must be
+    /// within a single allocation.
+    #[inline]
+    pub unsafe fn from_ptr_slice<'a>(ptr : *mut T, len : usize) -> &'a SuperCell<[T]> {
+        // SAFETY: The caller upholds the contract above and the layout is the same.
+        unsafe { &*(core::ptr::slice_from_raw_parts_mut(ptr, len) as *const SuperCell<[T]>) }
+    }
+
+    /// Consumes the box, returning a raw pointer to the value for handing over ownership
+    ///
+    /// The pointer must be given back to [`SuperCell::from_raw`] to free it.
+    #[inline]
+    pub fn into_raw(cell : Box<SuperCell<T>>) -> *mut T {
+        Box::into_raw(cell).cast::<T>()
+    }
+
+    /// Takes back ownership of a pointer returned by [`SuperCell::into_raw`]
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must come from [`SuperCell::into_raw`] (or `Box::<T>::into_raw`) and must not have
+    /// been freed or taken back already.
+    #[inline]
+    pub unsafe fn from_raw(ptr : *mut T) -> Box<SuperCell<T>> {
+        // SAFETY: The caller guarantees that `ptr` was allocated by a `Box` of the same layout.
+        unsafe { Box::from_raw(ptr.cast::<SuperCell<T>>()) }
+    }
+
+    /// Converts a `Box<[T]>` into a `Box<[SuperCell<T>]>` without copying or reallocating
+    ///
+    /// Combined with `Arc::from`, this is a convenient way of building an `Arc<[SuperCell<T>]>`
+    /// shared between threads, each writing their own elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use super_cell::SuperCell;
+    /// let shared : Arc<[SuperCell<u64>]> = Arc::from(SuperCell::wrap_boxed_slice(vec![0; 4].into_boxed_slice()));
+    /// *shared[3].get_mut() = 1;
+    /// assert_eq!(*shared[3].get(), 1);
+    /// ```
+    pub fn wrap_boxed_slice(slice : Box<[T]>) -> Box<[SuperCell<T>]> {
+        // SAFETY: `SuperCell<T>` has the same memory layout as `T`, so the allocation is reused as is.
+        unsafe { Box::from_raw(Box::into_raw(slice) as *mut [SuperCell<T>]) }
+    }
+
+    /// Converts a `Box<[SuperCell<T>]>` back into a `Box<[T]>` without copying or reallocating
+    pub fn unwrap_boxed_slice(slice : Box<[SuperCell<T>]>) -> Box<[T]> {
+        // SAFETY: `SuperCell<T>` has the same memory layout as `T`, so the allocation is reused as is.
+        unsafe { Box::from_raw(Box::into_raw(slice) as *mut [T]) }
+    }
+
+    /// Converts a `Box<T>` into a `Box<SuperCell<T>>` without copying or reallocating
+    pub fn wrap_boxed(value : Box<T>) -> Box<SuperCell<T>> {
+        // SAFETY: `SuperCell<T>` has the same memory layout as `T`, so the allocation is reused as is.
+        unsafe { Box::from_raw(Box::into_raw(value).cast::<SuperCell<T>>()) }
+    }
+
+    /// Converts a `Box<SuperCell<T>>` back into a `Box<T>` without copying or reallocating
+    pub fn unwrap_boxed(value : Box<SuperCell<T>>) -> Box<T> {
+        // SAFETY: `SuperCell<T>` has the same memory layout as `T`, so the allocation is reused as is.
+        unsafe { Box::from_raw(Box::into_raw(value).cast::<T>()) }
+    }
+
+    /// Moves `value` to the heap and leaks it, returning a cell that lives for the rest of the
+    /// program
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let services : &'static SuperCell<Vec<&str>> = SuperCell::leak(vec![]);
+    /// std::thread::spawn(move || services.push("logger")).join().unwrap();
+    /// assert_eq!(*services.get(), ["logger"]);
+    /// // SAFETY: `services` is not used after this.
+    /// drop(unsafe { SuperCell::from_leaked(services) });
+    /// ```
+    #[inline]
+    pub fn leak(value : T) -> &'static SuperCell<T> {
+        Box::leak(Box::new(SuperCell::new(value)))
+    }
+
+    /// Leaks the elements of `values`, returning a cell of the slice that lives for the rest of
+    /// the program
+    ///
+    /// Spare capacity is freed first.
+    #[inline]
+    pub fn leak_slice(values : Vec<T>) -> &'static SuperCell<[T]> {
+        SuperCell::from_mut(Box::leak(values.into_boxed_slice()))
+    }
+}
+
+impl<T : ?Sized> SuperCell<T> {
+    /// Takes back ownership of a cell returned by [`SuperCell::leak`] or
+    /// [`SuperCell::leak_slice`], so it is freed when the box drops
+    ///
+    /// # Safety
+    ///
+    /// `cell` must come from one of the leak functions, must not have been reclaimed already,
+    /// and neither it nor any copy of it may be used afterwards.
+    #[inline]
+    pub unsafe fn from_leaked(cell : &'static SuperCell<T>) -> Box<SuperCell<T>> {
+        // SAFETY: The caller guarantees the cell was leaked from a box and is no longer used.
+        unsafe { Box::from_raw(cell as *const SuperCell<T> as *mut SuperCell<T>) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::thread;
+    use super::*;
+
+    #[test]
+    fn vec_round_trip() {
+        let mut list = Vec::with_capacity(16);
+        list.extend([String::from("a"), String::from("b")]);
+        let pointer = list.as_ptr();
+        let cells = SuperCell::wrap_vec(list);
+        assert_eq!(cells.as_ptr().cast::<String>(), pointer);
+        assert_eq!(cells.capacity(), 16);
+        cells[0].get_mut().push('c');
+        let list = SuperCell::unwrap_vec(cells);
+        assert_eq!(list.as_ptr(), pointer);
+        assert_eq!(list, vec![String::from("ac"), String::from("b")]);
+    }
+
+    #[test]
+    fn slice_round_trip() {
+        let mut array = [1, 2, 3];
+        let pointer = array.as_ptr();
+        let cells = SuperCell::wrap_slice(&mut array);
+        assert_eq!(cells.as_ptr().cast::<i32>(), pointer);
+        *cells[2].get_mut() = 4;
+        assert_eq!(array, [1, 2, 4]);
+
+        let mut cells = vec![SuperCell::new(1), SuperCell::new(2)];
+        let plain = SuperCell::peel_slice(&mut cells);
+        plain.swap(0, 1);
+        assert_eq!(plain.as_ptr(), cells.as_ptr().cast::<i32>());
+        assert_eq!(cells, vec![SuperCell::new(2), SuperCell::new(1)]);
+    }
+
+    #[test]
+    fn boxed_round_trip() {
+        let boxed = vec![1u64, 2, 3].into_boxed_slice();
+        let pointer = boxed.as_ptr();
+        let cells = SuperCell::wrap_boxed_slice(boxed);
+        assert_eq!(cells.as_ptr().cast::<u64>(), pointer);
+        *cells[0].get_mut() = 4;
+        assert_eq!(&*SuperCell::unwrap_boxed_slice(cells), &[4, 2, 3]);
+
+        let boxed = Box::new(String::from("value"));
+        let pointer : *const String = &*boxed;
+        let cell = SuperCell::wrap_boxed(boxed);
+        assert_eq!(cell.as_ptr().cast_const(), pointer);
+        cell.get_mut().push('s');
+        assert_eq!(*SuperCell::unwrap_boxed(cell), "values");
+    }
+
+    #[test]
+    fn shared_boxed_slice() {
+        let shared : Arc<[SuperCell<u64>]> = Arc::from(SuperCell::wrap_boxed_slice(vec![0; 8].into_boxed_slice()));
+        thread::scope(|scope| {
+            for index in 0..shared.len() {
+                let shared = Arc::clone(&shared);
+                scope.spawn(move || *shared[index].get_mut() = index as u64 * 2);
+            }
+        });
+        assert_eq!(shared.iter().map(|cell| *cell.get()).collect::<Vec<_>>(), vec![0, 2, 4, 6, 8, 10, 12, 14]);
+    }
+
+    #[test]
+    fn leaked_config() {
+        #[derive(Debug, PartialEq)]
+        struct Config {
+            name : String,
+            workers : usize
+        }
+        static CONFIG : SuperCell<Option<&'static SuperCell<Config>>> = SuperCell::new(None);
+        CONFIG.set(Some(SuperCell::leak(Config { name : String::from("default"), workers : 1 })));
+        thread::spawn(|| {
+            let config = CONFIG.get().expect("Config should be registered");
+            config.get_mut().workers = 4;
+            config.get_mut().name.push_str("-tuned");
+        }).join().expect("Failed to join thread!");
+        let config = CONFIG.take().expect("Config should be registered");
+        assert_eq!(*config.get(), Config { name : String::from("default-tuned"), workers : 4 });
+        // SAFETY: The only copy was taken out of `CONFIG` above.
+        let reclaimed = unsafe { SuperCell::from_leaked(config) };
+        assert_eq!(reclaimed.into_inner().workers, 4);
+    }
+
+    #[test]
+    fn leaked_slice() {
+        let mut values = Vec::with_capacity(8);
+        values.extend([1, 2, 3]);
+        let slice = SuperCell::leak_slice(values);
+        thread::spawn(move || slice.as_slice_of_cells()[1].set(20)).join().expect("Failed to join thread!");
+        assert_eq!(*slice.get(), [1, 20, 3]);
+        // SAFETY: `slice` is not used after this.
+        drop(unsafe { SuperCell::from_leaked(slice) });
+    }
+
+    #[test]
+    fn existing_buffers() {
+        let mut array = [1, 2, 3, 4];
+        for cell in SuperCell::from_mut_array(&mut array).as_array_of_cells() {
+            *cell.get_mut() += 1;
+        }
+        assert_eq!(array, [2, 3, 4, 5]);
+
+        let mut list = vec![1, 2, 3];
+        for cell in SuperCell::from_mut_slice(list.as_mut_slice()).as_slice_of_cells() {
+            *cell.get_mut() *= 2;
+        }
+        assert_eq!(list, vec![2, 4, 6]);
+
+        let mut boxed = vec![String::new(); 2].into_boxed_slice();
+        let cells = SuperCell::from_mut_slice(&mut boxed).as_slice_of_cells();
+        cells[0].get_mut().push('a');
+        cells[1].get_mut().push('b');
+        assert_eq!(&*boxed, &[String::from("a"), String::from("b")]);
+    }
+
+    #[test]
+    fn ffi_flow() {
+        #[repr(C)]
+        struct Frame {
+            ready : SuperCell<u32>,
+            samples : [SuperCell<u16>; 4],
+        }
+
+        /// Stands in for C code filling a frame it was handed
+        extern "C" fn fill(frame : *mut Frame) {
+            // SAFETY: `frame` is valid and not borrowed during the call, and `SuperCell<u16>` has
+            // the layout of `u16`, as C sees it.
+            unsafe {
+                let samples = (*frame).samples.as_mut_ptr().cast::<u16>();
+                for index in 0..4 {
+                    samples.add(index).write(index as u16 * 3);
+                }
+                (*frame).ready.set(1);
+            }
+        }
+
+        /// Stands in for C code filling a plain buffer
+        extern "C" fn fill_slice(samples : *mut u16, len : usize) {
+            for index in 0..len {
+                // SAFETY: `samples` is valid for `len` writes.
+                unsafe { samples.add(index).write(index as u16 * 5) };
+            }
+        }
+
+        let raw = Box::into_raw(Box::new(Frame { ready: SuperCell::new(0), samples: Default::default() }));
+        fill(raw);
+        // SAFETY: `raw` comes from a live box, which is only accessed through this cell until it
+        // is freed below.
+        let frame = unsafe { SuperCell::from_ptr(raw) };
+        assert_eq!(*frame.get().ready.get(), 1);
+        let samples : Vec<u16> = frame.get().samples.iter().map(|sample| *sample.get()).collect();
+        assert_eq!(samples, [0, 3, 6, 9]);
+        // SAFETY: `frame` is not used anymore, and the box is only taken back once.
+        drop(unsafe { Box::from_raw(raw) });
+
+        let mut buffer = vec![0u16; 8];
+        let samples = buffer.as_mut_ptr();
+        fill_slice(samples, 8);
+        // SAFETY: The buffer holds 8 elements and is only accessed through the cells here.
+        let cells = unsafe { SuperCell::from_ptr_slice(samples, 8) };
+        assert_eq!(*cells.as_slice_of_cells()[7].get(), 35);
+
+        let owned = SuperCell::into_raw(Box::new(SuperCell::new(5u64)));
+        // SAFETY: `owned` comes from a live box and nothing else refers to it.
+        unsafe { *owned += 1 };
+        // SAFETY: `owned` comes from `into_raw` and is taken back once.
+        let owned = unsafe { SuperCell::from_raw(owned) };
+        assert_eq!(owned.into_inner(), 6);
+    }
+}
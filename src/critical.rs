@@ -0,0 +1,139 @@
+use core::marker::PhantomData;
+use crate::SuperCell;
+
+impl<T : ?Sized> SuperCell<T> {
+    /// Calls `f` with a mutable reference to the value inside a critical section
+    ///
+    /// On single core bare-metal targets this masks interrupts, so compound updates of a value
+    /// shared with an interrupt handler cannot be interleaved. This only helps if every other
+    /// access to the value is in a critical section as well, [`CriticalCell`] enforces that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// static TICKS : SuperCell<u64> = SuperCell::new(0);
+    /// TICKS.with_critical(|ticks| *ticks += 1);
+    /// assert_eq!(*TICKS.get(), 1);
+    /// ```
+    #[inline]
+    pub fn with_critical<R>(&self, f : impl FnOnce(&mut T) -> R) -> R {
+        critical_section::with(|_| f(self.get_mut()))
+    }
+}
+
+/// A cell whose value can only be accessed inside a critical section
+///
+/// Unlike `SuperCell`, this is only `Sync` when `T : Send`, like `critical_section::Mutex`.
+///
+/// # Examples
+///
+/// ```
+/// use super_cell::CriticalCell;
+/// static STATE : CriticalCell<[u8; 4]> = CriticalCell::new([0; 4]);
+/// STATE.with(|state| state[0] = 1);
+/// assert_eq!(STATE.with(|state| *state), [1, 0, 0, 0]);
+/// ```
+pub struct CriticalCell<T> {
+    cell : SuperCell<T>,
+    _no_auto_traits : PhantomData<*mut T>
+}
+
+unsafe impl<T : Send> Send for CriticalCell<T> {}
+unsafe impl<T : Send> Sync for CriticalCell<T> {}
+
+impl<T> CriticalCell<T> {
+    #[inline]
+    pub const fn new(value : T) -> Self {
+        Self {
+            cell : SuperCell::new(value),
+            _no_auto_traits : PhantomData
+        }
+    }
+
+    /// Calls `f` with a mutable reference to the value inside a critical section
+    #[inline]
+    pub fn with<R>(&self, f : impl FnOnce(&mut T) -> R) -> R {
+        self.cell.with_critical(f)
+    }
+
+    /// Replaces the value inside a critical section, returning the old one
+    #[inline]
+    pub fn replace(&self, value : T) -> T {
+        self.with(|current| core::mem::replace(current, value))
+    }
+
+    /// Sets the value inside a critical section, dropping the previous one
+    #[inline]
+    pub fn set(&self, value : T) {
+        drop(self.replace(value))
+    }
+
+    /// Returns a mutable reference to the value, with no critical section needed as the
+    /// borrow is exclusive
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        self.cell.value.get_mut()
+    }
+
+    /// Consumes the cell, returning the wrapped value
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.cell.into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use super::*;
+
+    const UPDATES : usize = 2000;
+
+    #[test]
+    fn mutual_exclusion() {
+        static STATE : CriticalCell<(usize, usize)> = CriticalCell::new((0, 0));
+        thread::scope(|scope| {
+            for _ in 0..2 {
+                scope.spawn(|| {
+                    for _ in 0..UPDATES {
+                        STATE.with(|state| {
+                            let seen = state.0;
+                            thread::yield_now();
+                            state.0 = seen + 1;
+                            state.1 += 1;
+                        });
+                    }
+                });
+            }
+        });
+        assert_eq!(STATE.with(|state| *state), (UPDATES * 2, UPDATES * 2));
+    }
+
+    #[test]
+    fn main_and_interrupt() {
+        let shared = SuperCell::new(vec![]);
+        thread::scope(|scope| {
+            let interrupt = scope.spawn(|| {
+                for event in 0..UPDATES {
+                    shared.with_critical(|events| events.push(event));
+                }
+            });
+            for _ in 0..UPDATES {
+                shared.with_critical(|events| {
+                    let len = events.len();
+                    thread::yield_now();
+                    assert_eq!(events.len(), len);
+                });
+            }
+            interrupt.join().expect("Failed to join thread!");
+        });
+        assert_eq!(*shared.get(), (0..UPDATES).collect::<Vec<_>>());
+
+        let mut cell = CriticalCell::new(1);
+        cell.set(2);
+        *cell.get_mut() += 1;
+        assert_eq!(cell.replace(4), 3);
+        assert_eq!(cell.into_inner(), 4);
+    }
+}
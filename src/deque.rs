@@ -0,0 +1,127 @@
+use alloc::collections::VecDeque;
+use core::slice;
+use crate::SuperCell;
+
+/// Queue operations and per element cell access for deque cells
+impl<T> SuperCell<VecDeque<T>> {
+    /// Returns the two halves of the deque as slices of cells, mirroring `VecDeque::as_slices`
+    ///
+    /// Like the `Vec` view, the slices are invalidated by anything that reallocates the deque or
+    /// moves its elements, including pushes that grow it and [`SuperCell::make_contiguous_cells`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use super_cell::SuperCell;
+    /// let queue = SuperCell::new(VecDeque::from([2, 3]));
+    /// queue.push_front(1);
+    /// let (front, back) = queue.as_slices_of_cells();
+    /// for cell in front.iter().chain(back) {
+    ///     *cell.get_mut() *= 10;
+    /// }
+    /// assert_eq!(queue.pop_front(), Some(10));
+    /// ```
+    pub fn as_slices_of_cells(&self) -> (&[SuperCell<T>], &[SuperCell<T>]) {
+        // The slices come from a mutable borrow so the cells may be written through.
+        let (front, back) = self.get_mut().as_mut_slices();
+        // SAFETY: `SuperCell<T>` has the same memory layout as `T`, see `as_slice_of_cells` on
+        // `SuperCell<Vec<T>>`.
+        unsafe {
+            (slice::from_raw_parts(front.as_mut_ptr().cast::<SuperCell<T>>(), front.len()),
+             slice::from_raw_parts(back.as_mut_ptr().cast::<SuperCell<T>>(), back.len()))
+        }
+    }
+
+    /// Rearranges the deque so its elements are contiguous, returning them as a cell of a slice
+    pub fn make_contiguous_cells(&self) -> &SuperCell<[T]> {
+        SuperCell::from_mut(self.get_mut().make_contiguous())
+    }
+
+    /// Appends `value` to the back of the queue
+    #[inline]
+    pub fn push_back(&self, value : T) {
+        self.get_mut().push_back(value)
+    }
+
+    /// Prepends `value` to the front of the queue
+    #[inline]
+    pub fn push_front(&self, value : T) {
+        self.get_mut().push_front(value)
+    }
+
+    /// Removes the last element and returns it
+    #[inline]
+    pub fn pop_back(&self) -> Option<T> {
+        self.get_mut().pop_back()
+    }
+
+    /// Removes the first element and returns it
+    #[inline]
+    pub fn pop_front(&self) -> Option<T> {
+        self.get_mut().pop_front()
+    }
+
+    /// Returns the number of elements in the queue
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.get().len()
+    }
+
+    /// Returns `true` if the queue has no elements
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.get().is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wrapped() -> SuperCell<VecDeque<u32>> {
+        let queue = SuperCell::new(VecDeque::with_capacity(4));
+        let capacity = queue.get().capacity();
+        for value in 0..capacity as u32 {
+            queue.push_back(value);
+        }
+        queue.pop_front();
+        queue.pop_front();
+        queue.push_back(100);
+        queue.push_back(101);
+        assert_eq!(queue.get().capacity(), capacity, "Queue should not have grown");
+        queue
+    }
+
+    #[test]
+    fn mutate_both_halves() {
+        let queue = wrapped();
+        let (front, back) = queue.as_slices_of_cells();
+        assert!(!front.is_empty() && !back.is_empty(), "Queue should have wrapped around");
+        for cell in front.iter().chain(back) {
+            *cell.get_mut() += 1;
+        }
+        let expected : Vec<u32> = queue.get().iter().copied().collect();
+        let mut popped = vec![];
+        while let Some(value) = queue.pop_front() {
+            popped.push(value);
+        }
+        assert_eq!(popped, expected);
+        assert_eq!(popped[popped.len() - 2..], [101, 102]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn contiguous_view() {
+        let queue = wrapped();
+        let length = queue.len();
+        let cells = queue.make_contiguous_cells().as_slice_of_cells();
+        assert_eq!(cells.len(), length);
+        cells[0].set(50);
+        assert!(queue.as_slices_of_cells().1.is_empty());
+        queue.push_front(1);
+        assert_eq!(queue.pop_front(), Some(1));
+        assert_eq!(queue.pop_front(), Some(50));
+        assert_eq!(queue.pop_back(), Some(101));
+    }
+}
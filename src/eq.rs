@@ -0,0 +1,97 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use crate::SuperCell;
+
+/// Implements `PartialEq` between a cell and plain data, in both operand orders, comparing the
+/// value of the cell as `$view` with the other side as `$view` as well
+macro_rules! impl_eq {
+    ($([$($generics:tt)*] $cell:ty, $plain:ty => $view:ty;)*) => {$(
+        impl<$($generics)*> PartialEq<$plain> for SuperCell<$cell> {
+            #[inline]
+            fn eq(&self, other : &$plain) -> bool {
+                <$view as PartialEq>::eq(&self.get()[..], &other[..])
+            }
+        }
+
+        impl<$($generics)*> PartialEq<SuperCell<$cell>> for $plain {
+            #[inline]
+            fn eq(&self, other : &SuperCell<$cell>) -> bool {
+                <$view as PartialEq>::eq(&self[..], &other.get()[..])
+            }
+        }
+    )*};
+}
+
+impl_eq! {
+    [T : PartialEq, const N : usize] [T; N], [T; N] => [T];
+    [T : PartialEq] [T], [T] => [T];
+    [T : PartialEq, const N : usize] [T], [T; N] => [T];
+    [T : PartialEq] Vec<T>, Vec<T> => [T];
+    [T : PartialEq] Vec<T>, [T] => [T];
+    ['a, T : PartialEq] Vec<T>, &'a [T] => [T];
+    [T : PartialEq, const N : usize] Vec<T>, [T; N] => [T];
+    [] String, String => str;
+    [] String, str => str;
+    ['a] String, &'a str => str;
+    [] str, str => str;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arrays_and_slices() {
+        let array = SuperCell::new([1, 2, 3]);
+        assert_eq!(array, [1, 2, 3]);
+        assert_eq!([1, 2, 3], array);
+        assert_ne!(array, [1, 2, 4]);
+
+        let mut values = [1, 2, 3];
+        let slice = SuperCell::from_mut_slice(&mut values);
+        assert_eq!(*slice, [1, 2, 3]);
+        assert_eq!(*slice, *[1, 2, 3].as_slice());
+        assert_eq!(*[1, 2, 3].as_slice(), *slice);
+        assert_ne!(*slice, [1, 2]);
+        assert_ne!([1, 2, 3, 4], *slice);
+        slice.as_slice_of_cells()[0].set(5);
+        assert_eq!(*slice, [5, 2, 3]);
+    }
+
+    #[test]
+    fn vecs() {
+        let list = SuperCell::new(vec![1, 2]);
+        assert_eq!(list, vec![1, 2]);
+        assert_eq!(vec![1, 2], list);
+        assert_eq!(list, [1, 2]);
+        assert_eq!([1, 2], list);
+        assert_eq!(list, &[1, 2][..]);
+        assert_eq!(&[1, 2][..], list);
+        assert_eq!(list, *[1, 2].as_slice());
+        assert_ne!(list, vec![1, 2, 3]);
+        assert_ne!(Vec::<i32>::new(), list);
+        list.push(3);
+        assert_eq!(list, [1, 2, 3]);
+    }
+
+    #[test]
+    fn strings() {
+        let text = SuperCell::new(String::from("abc"));
+        assert_eq!(text, "abc");
+        assert_eq!("abc", text);
+        assert_eq!(text, *"abc");
+        assert_eq!(*"abc", text);
+        assert_eq!(text, String::from("abc"));
+        assert_eq!(String::from("abc"), text);
+        assert_ne!(text, "ab");
+        assert_ne!("abcd", text);
+
+        let mut bytes = *b"abc";
+        let text = core::str::from_utf8_mut(&mut bytes).unwrap();
+        // SAFETY: The string is valid and borrowed for the whole test.
+        let cell : &SuperCell<str> = unsafe { &*(text as *mut str as *const SuperCell<str>) };
+        assert_eq!(*cell, *"abc");
+        assert_eq!(*"abc", *cell);
+        assert_ne!(*cell, *"abd");
+    }
+}
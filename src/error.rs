@@ -0,0 +1,107 @@
+//! Errors of the checked accessors, like [`SuperCell::try_borrow`](crate::SuperCell::try_borrow)
+//!
+//! Only the `strict` feature can make these fail. Without it nothing is tracked, so the
+//! fallible accessors always succeed.
+
+use core::fmt::{self, Display, Formatter};
+use core::panic::Location;
+
+/// Kind of borrow an access conflicted with
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccessKind {
+    /// One or more shared borrows, from `borrow`
+    Shared,
+    /// A mutable borrow, from `borrow_mut`
+    Exclusive
+}
+
+impl AccessKind {
+    /// Message for an access conflicting with a borrow of this kind
+    pub(crate) const fn conflict_message(self) -> &'static str {
+        match self {
+            AccessKind::Shared => "already borrowed",
+            AccessKind::Exclusive => "already mutably borrowed"
+        }
+    }
+}
+
+/// The value could not be borrowed for reading, as it is mutably borrowed
+#[derive(Clone, Copy, Debug)]
+pub struct BorrowError {
+    conflict : Conflict
+}
+
+/// The value could not be borrowed for writing, as it is already borrowed
+#[derive(Clone, Copy, Debug)]
+pub struct BorrowMutError {
+    conflict : Conflict
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Conflict {
+    kind : AccessKind,
+    #[cfg(debug_assertions)]
+    location : &'static Location<'static>
+}
+
+impl Conflict {
+    #[cfg_attr(not(debug_assertions), allow(unused_variables))]
+    fn new(kind : AccessKind, location : &'static Location<'static>) -> Self {
+        Self {
+            kind,
+            #[cfg(debug_assertions)]
+            location
+        }
+    }
+
+    fn location(&self) -> Option<&'static Location<'static>> {
+        #[cfg(debug_assertions)]
+        return Some(self.location);
+        #[cfg(not(debug_assertions))]
+        None
+    }
+}
+
+impl Display for Conflict {
+    fn fmt(&self, f : &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.kind.conflict_message())?;
+        match self.location() {
+            Some(location) => write!(f, ": borrowed at {location}"),
+            None => Ok(())
+        }
+    }
+}
+
+macro_rules! impl_error {
+    ($($error:ident),*) => {$(
+        impl $error {
+            #[cfg_attr(not(feature = "strict"), allow(dead_code))]
+            pub(crate) fn new(kind : AccessKind, location : &'static Location<'static>) -> Self {
+                Self { conflict : Conflict::new(kind, location) }
+            }
+
+            /// Returns the kind of the borrow which is in the way
+            #[inline]
+            pub fn conflicting(&self) -> AccessKind {
+                self.conflict.kind
+            }
+
+            /// Returns where the borrow in the way was taken, only known in builds with
+            /// `debug_assertions`
+            #[inline]
+            pub fn location(&self) -> Option<&'static Location<'static>> {
+                self.conflict.location()
+            }
+        }
+
+        impl Display for $error {
+            fn fmt(&self, f : &mut Formatter<'_>) -> fmt::Result {
+                self.conflict.fmt(f)
+            }
+        }
+
+        impl core::error::Error for $error {}
+    )*};
+}
+
+impl_error!(BorrowError, BorrowMutError);
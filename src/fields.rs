@@ -0,0 +1,43 @@
+use crate::SuperCell;
+
+/// Types whose cell can be split into one cell per field, usually through
+/// `#[derive(CellProject)]`
+///
+/// An inherent impl on `SuperCell<Player>` can only be written inside this crate, so the derive
+/// instead generates a `PlayerCells` view with one `&SuperCell<Field>` per field, returned by
+/// [`SuperCell::fields`]. The view fields share the visibility of the struct fields.
+///
+/// # Examples
+///
+/// ```
+/// use super_cell::{project, CellProject, SuperCell};
+/// struct Player { hp : u32, name : String }
+/// struct PlayerCells<'a> { hp : &'a SuperCell<u32>, name : &'a SuperCell<String> }
+///
+/// impl CellProject for Player {
+///     type Cells<'a> = PlayerCells<'a>;
+///     fn project(cell : &SuperCell<Self>) -> PlayerCells<'_> {
+///         PlayerCells { hp : project!(cell => hp), name : project!(cell => name) }
+///     }
+/// }
+///
+/// let player = SuperCell::new(Player { hp : 10, name : String::from("a") });
+/// let fields = player.fields();
+/// *fields.hp.get_mut() -= 1;
+/// fields.name.get_mut().push('b');
+/// assert_eq!(player.get().hp, 9);
+/// assert_eq!(player.get().name, "ab");
+/// ```
+pub trait CellProject {
+    type Cells<'a> where Self : 'a;
+
+    fn project(cell : &SuperCell<Self>) -> Self::Cells<'_>;
+}
+
+impl<T : CellProject> SuperCell<T> {
+    /// Returns the cells of every field of the value, see [`CellProject`]
+    #[inline]
+    pub fn fields(&self) -> T::Cells<'_> {
+        T::project(self)
+    }
+}
@@ -0,0 +1,138 @@
+use core::iter::{Product, Sum};
+use crate::SuperCell;
+
+impl<T> SuperCell<[T]> {
+    /// Iterator reading each element once, through the pointer of the cell so no reference to
+    /// the slice is held between reads
+    fn read_each(&self) -> impl Iterator<Item = T> + '_ where T : Copy {
+        let elements = self.as_ptr().cast::<T>();
+        // SAFETY: Every index is in bounds, and elements are `Copy` so reading does not move out.
+        (0..self.as_ptr().len()).map(move |index| unsafe { elements.add(index).read() })
+    }
+
+    /// Folds a copy of every element into an accumulator, in order
+    ///
+    /// Each element is read once, right before `f` is called with it, and no reference to the
+    /// slice is held while `f` runs, so `f` may write to elements it has already seen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let mut values = [1, 2, 3];
+    /// let cell = SuperCell::from_mut_slice(&mut values);
+    /// assert_eq!(cell.fold(String::new(), |text, value| text + &value.to_string()), "123");
+    /// ```
+    #[inline]
+    pub fn fold<B>(&self, init : B, f : impl FnMut(B, T) -> B) -> B where T : Copy {
+        self.read_each().fold(init, f)
+    }
+
+    /// Returns the sum of the elements, `T::sum` of an empty iterator if there are none
+    ///
+    /// Adds in order with `T`'s `Sum`, so integer overflow panics in debug builds. The `simd`
+    /// feature adds a vectorized `sum` for primitive slices.
+    #[inline]
+    pub fn sum_elements(&self) -> T where T : Copy + Sum<T> {
+        self.read_each().sum()
+    }
+
+    /// Returns the index and value of the largest element, or `None` if there are none
+    ///
+    /// The first of several equal largest elements is returned. An element only replaces the
+    /// current maximum if it compares greater, so values which do not compare, like NaN, are
+    /// skipped unless they come first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let mut heights = [1.5, 4.0, 2.0, 4.0];
+    /// assert_eq!(SuperCell::from_mut_slice(&mut heights).max_element(), Some((1, 4.0)));
+    /// ```
+    pub fn max_element(&self) -> Option<(usize, T)> where T : Copy + PartialOrd {
+        self.read_each().enumerate().fold(None, |max, (index, value)| match max {
+            Some((_, current)) if value > current => Some((index, value)),
+            None => Some((index, value)),
+            _ => max
+        })
+    }
+}
+
+/// Implements `Sum` and `Product` of `&SuperCell<T>` items for primitives, which a blanket impl
+/// over every `T` cannot do for a foreign trait
+macro_rules! impl_sum_product {
+    ($($ty:ty),*) => {$(
+        impl<'a> Sum<&'a SuperCell<$ty>> for $ty {
+            #[inline]
+            fn sum<I : Iterator<Item = &'a SuperCell<$ty>>>(iter : I) -> $ty {
+                iter.map(|cell| *cell.get()).sum()
+            }
+        }
+
+        impl<'a> Product<&'a SuperCell<$ty>> for $ty {
+            #[inline]
+            fn product<I : Iterator<Item = &'a SuperCell<$ty>>>(iter : I) -> $ty {
+                iter.map(|cell| *cell.get()).product()
+            }
+        }
+    )*};
+}
+
+impl_sum_product!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iterator_sum_and_product() {
+        let cells = SuperCell::wrap_vec(vec![2u64, 3, 4]);
+        assert_eq!(cells.iter().sum::<u64>(), 9);
+        assert_eq!(cells.iter().product::<u64>(), 24);
+        let mut values = [0.5f32, 4.0];
+        let slice = SuperCell::from_mut_slice(&mut values);
+        assert_eq!(slice.as_slice_of_cells().iter().product::<f32>(), 2.0);
+        let empty : [SuperCell<i32>; 0] = [];
+        assert_eq!(empty.iter().sum::<i32>(), 0);
+        assert_eq!(empty.iter().product::<i32>(), 1);
+    }
+
+    #[test]
+    fn slices_match_plain() {
+        let sets : [&[i64]; 5] = [&[], &[-7], &[3, -1, 3], &[5, 9, 9, 2], &[i64::MIN, 0, i64::MAX]];
+        for set in sets {
+            let mut values = set.to_vec();
+            let cell = SuperCell::from_mut_slice(&mut values);
+            let plain_max = set.iter().enumerate().rev().max_by_key(|(_, value)| **value).map(|(index, value)| (index, *value));
+            assert_eq!(cell.max_element(), plain_max, "{set:?}");
+            assert_eq!(cell.fold(Vec::new(), |mut seen, value| {
+                seen.push(value);
+                seen
+            }), set);
+            assert_eq!(cell.sum_elements(), set.iter().sum::<i64>());
+        }
+    }
+
+    #[test]
+    fn fold_may_write() {
+        let mut values = [1u32, 2, 3];
+        let cell = SuperCell::from_mut_slice(&mut values);
+        let total = cell.fold(0, |total, value| {
+            cell.as_slice_of_cells()[0].set(value * 10);
+            total + value
+        });
+        assert_eq!(total, 6);
+        assert_eq!(values, [30, 2, 3]);
+    }
+
+    #[test]
+    fn floats_with_nan() {
+        let mut values = [f64::NAN, 1.0, 3.0];
+        assert!(SuperCell::from_mut_slice(&mut values).max_element().unwrap().1.is_nan());
+        let mut values = [1.0, f64::NAN, 3.0, -2.0];
+        let cell = SuperCell::from_mut_slice(&mut values);
+        assert_eq!(cell.max_element(), Some((2, 3.0)));
+        assert!(cell.sum_elements().is_nan());
+    }
+}
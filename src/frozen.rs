@@ -0,0 +1,166 @@
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use crate::SuperCell;
+
+/// `SuperCell` which has given up mutation, for use as a map key
+///
+/// Only shared access to the value is available, so a key cannot be changed behind the back of
+/// the map holding it. The value is stored without an `UnsafeCell`, unlike in `SuperCell`.
+/// Comparisons and hashing go to the value, and `Borrow<T>` allows lookups by `&T`.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use super_cell::SuperCell;
+/// let mut scores = HashMap::new();
+/// scores.insert(SuperCell::new([1, 2]).freeze(), "a");
+/// assert_eq!(scores.get(&[1, 2]), Some(&"a"));
+/// ```
+///
+/// A frozen cell cannot be written to:
+///
+/// ```compile_fail
+/// use super_cell::SuperCell;
+/// let frozen = SuperCell::new(1).freeze();
+/// *frozen.get_mut() = 2;
+/// ```
+///
+/// ```compile_fail
+/// use super_cell::SuperCell;
+/// let frozen = SuperCell::new(1).freeze();
+/// frozen.set(2);
+/// ```
+#[repr(transparent)]
+pub struct FrozenCell<T : ?Sized> {
+    value : T
+}
+
+impl<T> SuperCell<T> {
+    /// Turns the cell into a [`FrozenCell`], which only allows shared access
+    #[inline]
+    pub fn freeze(self) -> FrozenCell<T> {
+        FrozenCell::new(self.into_inner())
+    }
+}
+
+impl<T : ?Sized> SuperCell<T> {
+    /// Views the cell as a [`FrozenCell`], for looking up frozen keys by a cell
+    ///
+    /// Like [`get`](SuperCell::get), the value must not be mutated while the view is alive.
+    #[inline]
+    pub fn freeze_ref(&self) -> &FrozenCell<T> {
+        // SAFETY: `FrozenCell<T>` is `repr(transparent)` over `T`, and mutations are excluded
+        // for the lifetime of the borrow like for `get`.
+        unsafe { &*(self.as_ptr() as *const FrozenCell<T>) }
+    }
+}
+
+impl<T> FrozenCell<T> {
+    #[inline]
+    pub const fn new(value : T) -> Self {
+        Self { value }
+    }
+
+    /// Turns the cell back into a mutable `SuperCell`
+    #[inline]
+    pub fn thaw(self) -> SuperCell<T> {
+        SuperCell::new(self.value)
+    }
+
+    /// Consumes the cell, returning the wrapped value
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T : ?Sized> FrozenCell<T> {
+    /// Returns a shared reference to the value
+    #[inline]
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T : ?Sized> Borrow<T> for FrozenCell<T> {
+    #[inline]
+    fn borrow(&self) -> &T {
+        self.get()
+    }
+}
+
+impl<T : PartialEq + ?Sized> PartialEq for FrozenCell<T> {
+    fn eq(&self, other : &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl<T : Eq + ?Sized> Eq for FrozenCell<T> {}
+
+impl<T : PartialOrd + ?Sized> PartialOrd for FrozenCell<T> {
+    fn partial_cmp(&self, other : &Self) -> Option<Ordering> {
+        self.get().partial_cmp(other.get())
+    }
+}
+
+impl<T : Ord + ?Sized> Ord for FrozenCell<T> {
+    fn cmp(&self, other : &Self) -> Ordering {
+        self.get().cmp(other.get())
+    }
+}
+
+impl<T : Hash + ?Sized> Hash for FrozenCell<T> {
+    fn hash<H : Hasher>(&self, state : &mut H) {
+        self.get().hash(state)
+    }
+}
+
+impl<T : Clone> Clone for FrozenCell<T> {
+    fn clone(&self) -> Self {
+        FrozenCell::new(self.get().clone())
+    }
+}
+
+impl<T : Debug + ?Sized> Debug for FrozenCell<T> {
+    fn fmt(&self, f : &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("FrozenCell").field(&self.get()).finish()
+    }
+}
+
+impl<T : Display + ?Sized> Display for FrozenCell<T> {
+    fn fmt(&self, f : &mut Formatter<'_>) -> core::fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+    use super::*;
+
+    #[test]
+    fn hash_map_keys() {
+        let mut map = HashMap::new();
+        map.insert(SuperCell::new(String::from("a")).freeze(), 1);
+        map.insert(FrozenCell::new(String::from("b")), 2);
+        assert_eq!(map.get(&String::from("b")), Some(&2));
+        let probe = SuperCell::new(String::from("a"));
+        assert_eq!(map.get(probe.freeze_ref()), Some(&1));
+        probe.get_mut().push('!');
+        assert_eq!(map.get(probe.freeze_ref()), None);
+    }
+
+    #[test]
+    fn b_tree_map_keys() {
+        let map : BTreeMap<_, _> = [3, 1, 2].into_iter().map(|key| (FrozenCell::new(key), key * 10)).collect();
+        assert_eq!(map.keys().map(|key| *key.get()).collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(map.get(&2), Some(&20));
+        let (key, _) = map.into_iter().next().unwrap();
+        let cell = key.thaw();
+        cell.set(5);
+        assert_eq!(cell.freeze().into_inner(), 5);
+    }
+}
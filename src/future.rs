@@ -0,0 +1,147 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use crate::SuperCell;
+
+impl<F : Future> SuperCell<F> {
+    /// Polls the future in this pinned cell
+    ///
+    /// Pinning is structural, a pinned `SuperCell<F>` pins its future. Since every other method
+    /// takes `&self`, and a `Pin<&SuperCell<F>>` dereferences to one, the pin alone cannot stop
+    /// them from moving the future out. [`poll_unpin`](SuperCell::poll_unpin) is the safe
+    /// alternative for `Unpin` futures.
+    ///
+    /// # Safety
+    ///
+    /// Once this is called, the future must not be moved out of the cell until it is dropped, by
+    /// [`replace`](SuperCell::replace), [`take`](SuperCell::take), `swap` or through
+    /// [`get_mut`](SuperCell::get_mut). [`set`](SuperCell::set) is fine, since it drops the old
+    /// future in place. The future must not be accessed while it is being polled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::pin::pin;
+    /// use std::task::{Context, Poll, Waker};
+    /// use super_cell::SuperCell;
+    /// let task = pin!(SuperCell::new(async { 1 + 2 }));
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// // SAFETY: The future is only ever polled.
+    /// assert_eq!(unsafe { task.as_ref().poll_inner(&mut cx) }, Poll::Ready(3));
+    /// ```
+    #[inline]
+    pub unsafe fn poll_inner(self : Pin<&Self>, cx : &mut Context<'_>) -> Poll<F::Output> {
+        // SAFETY: The cell is pinned, and so is the future under structural pinning. The caller
+        // guarantees it is not moved out afterwards nor aliased while polling.
+        unsafe { Pin::new_unchecked(self.get_ref().get_mut()) }.poll(cx)
+    }
+
+    /// Polls the `Unpin` future in this cell, which may be moved freely between polls
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::future::ready;
+    /// use std::task::{Context, Poll, Waker};
+    /// use super_cell::SuperCell;
+    /// let task = SuperCell::new(ready(3));
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// assert_eq!(task.poll_unpin(&mut cx), Poll::Ready(3));
+    /// ```
+    #[inline]
+    pub fn poll_unpin(&self, cx : &mut Context<'_>) -> Poll<F::Output> where F : Unpin {
+        Pin::new(self.get_mut()).poll(cx)
+    }
+
+    /// Polls the future in this cell without requiring a pin
+    ///
+    /// For futures stored in arenas or other places which never move their values.
+    ///
+    /// # Safety
+    ///
+    /// Once this is called, the cell must not move and its future must not be moved out until it
+    /// is dropped, as if the cell was pinned, see [`poll_inner`](SuperCell::poll_inner). The
+    /// future must not be accessed while it is being polled.
+    #[inline]
+    pub unsafe fn poll_unpin_unchecked(&self, cx : &mut Context<'_>) -> Poll<F::Output> {
+        // SAFETY: Guaranteed by the caller, which is the contract of `poll_inner` as well.
+        unsafe { Pin::new_unchecked(self).poll_inner(cx) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::marker::PhantomPinned;
+    use std::pin::pin;
+    use std::task::Waker;
+    use super::*;
+
+    /// Ready with the number of polls after `left` pending ones, and `!Unpin` like most futures
+    struct Countdown {
+        left : usize,
+        polls : usize,
+        _pinned : PhantomPinned
+    }
+
+    impl Countdown {
+        fn new(left : usize) -> Self {
+            Self { left, polls : 0, _pinned : PhantomPinned }
+        }
+    }
+
+    impl Future for Countdown {
+        type Output = usize;
+
+        fn poll(self : Pin<&mut Self>, cx : &mut Context<'_>) -> Poll<usize> {
+            // SAFETY: No field is structurally pinned.
+            let this = unsafe { self.get_unchecked_mut() };
+            this.polls += 1;
+            if this.left == 0 {
+                return Poll::Ready(this.polls);
+            }
+            this.left -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn pinned_to_completion() {
+        let mut cx = Context::from_waker(Waker::noop());
+        let task = pin!(SuperCell::new(Countdown::new(3)));
+        for _ in 0..3 {
+            // SAFETY: The task is only polled.
+            assert_eq!(unsafe { task.as_ref().poll_inner(&mut cx) }, Poll::Pending);
+        }
+        // SAFETY: As above.
+        assert_eq!(unsafe { task.as_ref().poll_inner(&mut cx) }, Poll::Ready(4));
+    }
+
+    #[test]
+    fn unpin_moved_between_polls() {
+        let mut cx = Context::from_waker(Waker::noop());
+        let task = SuperCell::new(Box::pin(Countdown::new(1)));
+        assert_eq!(task.poll_unpin(&mut cx), Poll::Pending);
+        let moved = SuperCell::new(task.replace(Box::pin(Countdown::new(0))));
+        assert_eq!(moved.poll_unpin(&mut cx), Poll::Ready(2));
+        assert_eq!(task.poll_unpin(&mut cx), Poll::Ready(1));
+    }
+
+    #[test]
+    fn arena_to_completion() {
+        let mut cx = Context::from_waker(Waker::noop());
+        let arena : Vec<_> = (0..4).map(|left| SuperCell::new(Countdown::new(left))).collect();
+        let mut done = vec![None; arena.len()];
+        while done.iter().any(Option::is_none) {
+            for (task, result) in arena.iter().zip(&mut done) {
+                if result.is_none() {
+                    // SAFETY: The arena is never resized or moved, and the tasks are only polled.
+                    if let Poll::Ready(polls) = unsafe { task.poll_unpin_unchecked(&mut cx) } {
+                        *result = Some(polls);
+                    }
+                }
+            }
+        }
+        assert_eq!(done, [Some(1), Some(2), Some(3), Some(4)]);
+    }
+}
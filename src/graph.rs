@@ -0,0 +1,284 @@
+//! Graph and list nodes linked through cells, allocated from an arena
+//!
+//! Nodes are handed out as `&'a Node<'a, T>` where `'a` is the borrow of the arena, and links are
+//! plain references with the same lifetime, so the borrow checker guarantees no link outlives
+//! the arena. Nodes never move once allocated, the arena grows by adding chunks instead of
+//! reallocating the existing ones.
+//!
+//! # Examples
+//!
+//! ```
+//! use super_cell::graph::NodeArena;
+//! let arena = NodeArena::new();
+//! let a = arena.alloc(1);
+//! let b = arena.alloc(2);
+//! a.connect(b);
+//! b.connect(a);
+//! for neighbor in a.neighbors() {
+//!     *neighbor.value().get_mut() += 10;
+//! }
+//! assert_eq!(*b.value().get(), 12);
+//! ```
+
+use alloc::vec::Vec;
+use crate::SuperCell;
+
+const FIRST_CHUNK : usize = 8;
+
+/// Arena owning every `Node` allocated from it
+pub struct NodeArena<'a, T> {
+    chunks : SuperCell<Vec<Vec<Node<'a, T>>>>
+}
+
+/// Node holding a payload, outgoing edges and optional list links
+pub struct Node<'a, T> {
+    value : SuperCell<T>,
+    edges : SuperCell<Vec<&'a Node<'a, T>>>,
+    prev : SuperCell<Option<&'a Node<'a, T>>>,
+    next : SuperCell<Option<&'a Node<'a, T>>>
+}
+
+/// Doubly linked list of nodes allocated from a borrowed `NodeArena`
+pub struct NodeList<'a, T> {
+    arena : &'a NodeArena<'a, T>,
+    head : SuperCell<Option<&'a Node<'a, T>>>,
+    tail : SuperCell<Option<&'a Node<'a, T>>>
+}
+
+/// Iterator over the outgoing edges of a node, returned by [`Node::neighbors`]
+///
+/// Edges are looked up by index on every step, so connecting more nodes while iterating is
+/// fine and the new edges are visited too.
+pub struct Neighbors<'a, T> {
+    node : &'a Node<'a, T>,
+    index : usize
+}
+
+/// Iterator over the nodes of a `NodeList`, returned by [`NodeList::iter`]
+pub struct Iter<'a, T> {
+    current : Option<&'a Node<'a, T>>
+}
+
+impl<'a, T> NodeArena<'a, T> {
+    pub const fn new() -> Self {
+        Self {
+            chunks : SuperCell::new(Vec::new())
+        }
+    }
+
+    /// Allocates a new node holding `value` with no edges
+    pub fn alloc(&'a self, value : T) -> &'a Node<'a, T> {
+        let chunks = self.chunks.get_mut();
+        let full = chunks.last().is_none_or(|chunk| chunk.len() == chunk.capacity());
+        if full {
+            let capacity = chunks.last().map_or(FIRST_CHUNK, |chunk| chunk.capacity() * 2);
+            chunks.push(Vec::with_capacity(capacity));
+        }
+        let chunk = chunks.last_mut().expect("a chunk with room was just ensured");
+        chunk.push(Node {
+            value : SuperCell::new(value),
+            edges : SuperCell::new(Vec::new()),
+            prev : SuperCell::new(None),
+            next : SuperCell::new(None)
+        });
+        let node : *const Node<'a, T> = chunk.last().expect("a node was just pushed");
+        // SAFETY: Chunks never grow past their capacity, so the node is never moved, and it is
+        // only dropped along with the arena, which `'a` borrows.
+        unsafe { &*node }
+    }
+
+    /// Returns the number of nodes allocated so far
+    pub fn len(&self) -> usize {
+        self.chunks.get().iter().map(Vec::len).sum()
+    }
+
+    /// Returns `true` if no nodes have been allocated
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Default for NodeArena<'_, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> Node<'a, T> {
+    /// Returns the cell holding the payload
+    #[inline]
+    pub fn value(&self) -> &SuperCell<T> {
+        &self.value
+    }
+
+    /// Adds an edge from this node to `other`
+    #[inline]
+    pub fn connect(&self, other : &'a Node<'a, T>) {
+        self.edges.get_mut().push(other);
+    }
+
+    /// Returns an iterator over the nodes this one has edges to, in the order they were added
+    #[inline]
+    pub fn neighbors(&'a self) -> Neighbors<'a, T> {
+        Neighbors { node : self, index : 0 }
+    }
+
+    /// Returns the next node in the list this node belongs to
+    #[inline]
+    pub fn next(&self) -> Option<&'a Node<'a, T>> {
+        *self.next.get()
+    }
+
+    /// Returns the previous node in the list this node belongs to
+    #[inline]
+    pub fn prev(&self) -> Option<&'a Node<'a, T>> {
+        *self.prev.get()
+    }
+}
+
+impl<'a, T> Iterator for Neighbors<'a, T> {
+    type Item = &'a Node<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let neighbor = self.node.edges.get().get(self.index).copied()?;
+        self.index += 1;
+        Some(neighbor)
+    }
+}
+
+impl<'a, T> NodeList<'a, T> {
+    /// Creates an empty list allocating its nodes from `arena`
+    pub fn new(arena : &'a NodeArena<'a, T>) -> Self {
+        Self {
+            arena,
+            head : SuperCell::new(None),
+            tail : SuperCell::new(None)
+        }
+    }
+
+    /// Allocates a node holding `value` and links it at the front of the list
+    pub fn push_front(&self, value : T) -> &'a Node<'a, T> {
+        let node = self.arena.alloc(value);
+        match self.head.replace(Some(node)) {
+            Some(head) => {
+                node.next.set(Some(head));
+                head.prev.set(Some(node));
+            }
+            None => self.tail.set(Some(node))
+        }
+        node
+    }
+
+    /// Allocates a node holding `value` and links it at the back of the list
+    pub fn push_back(&self, value : T) -> &'a Node<'a, T> {
+        let node = self.arena.alloc(value);
+        match self.tail.replace(Some(node)) {
+            Some(tail) => {
+                node.prev.set(Some(tail));
+                tail.next.set(Some(node));
+            }
+            None => self.head.set(Some(node))
+        }
+        node
+    }
+
+    /// Returns the first node of the list
+    #[inline]
+    pub fn front(&self) -> Option<&'a Node<'a, T>> {
+        *self.head.get()
+    }
+
+    /// Returns the last node of the list
+    #[inline]
+    pub fn back(&self) -> Option<&'a Node<'a, T>> {
+        *self.tail.get()
+    }
+
+    /// Returns an iterator over the nodes from front to back
+    #[inline]
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter { current : self.front() }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a Node<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.current?;
+        self.current = node.next();
+        Some(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cyclic_graph() {
+        let arena = NodeArena::new();
+        let nodes : Vec<_> = (0..4).map(|value| arena.alloc(value)).collect();
+        for (index, node) in nodes.iter().enumerate() {
+            node.connect(nodes[(index + 1) % nodes.len()]);
+        }
+        nodes[0].connect(nodes[2]);
+
+        let mut current = nodes[0];
+        let mut visited = vec![];
+        for _ in 0..8 {
+            visited.push(*current.value().get());
+            current.value().with_mut(|value| *value += 10);
+            current = current.neighbors().next().expect("Every node has an edge");
+        }
+        assert_eq!(visited, [0, 1, 2, 3, 10, 11, 12, 13]);
+        assert_eq!(nodes[0].neighbors().map(|node| *node.value().get()).collect::<Vec<_>>(), [21, 22]);
+        assert_eq!(arena.len(), 4);
+    }
+
+    #[test]
+    fn connect_during_traversal() {
+        let arena = NodeArena::new();
+        let root = arena.alloc(0);
+        root.connect(arena.alloc(1));
+        let mut seen = 0;
+        for neighbor in root.neighbors() {
+            seen += 1;
+            if *neighbor.value().get() < 3 {
+                root.connect(arena.alloc(*neighbor.value().get() + 1));
+            }
+        }
+        assert_eq!(seen, 3);
+    }
+
+    #[test]
+    fn grows_without_moving_nodes() {
+        let arena = NodeArena::new();
+        let first = arena.alloc(String::from("first"));
+        let mut previous = first;
+        for index in 0..100 {
+            let node = arena.alloc(index.to_string());
+            previous.connect(node);
+            previous = node;
+        }
+        assert_eq!(first.value().get(), "first");
+        assert_eq!(first.neighbors().next().map(|node| node.value().get().as_str()), Some("0"));
+        assert_eq!(arena.len(), 101);
+    }
+
+    #[test]
+    fn list_links() {
+        let arena = NodeArena::new();
+        let list = NodeList::new(&arena);
+        list.push_back(2);
+        list.push_front(1);
+        let last = list.push_back(3);
+        for node in list.iter() {
+            *node.value().get_mut() *= 10;
+        }
+        assert_eq!(list.iter().map(|node| *node.value().get()).collect::<Vec<_>>(), [10, 20, 30]);
+        assert_eq!(last.prev().and_then(Node::prev).map(|node| *node.value().get()), Some(10));
+        assert_eq!(list.back().map(|node| *node.value().get()), Some(30));
+        assert!(list.front().and_then(Node::prev).is_none());
+    }
+}
@@ -0,0 +1,164 @@
+use core::hash::Hasher;
+use crate::SuperCell;
+
+/// Adapter returned by [`SuperCell::hasher`] forwarding `Hasher` calls to the hasher in a cell,
+/// for places where a `&mut impl Hasher` is needed
+#[derive(Clone, Copy)]
+pub struct CellHasher<'a, H> {
+    cell : &'a SuperCell<H>
+}
+
+impl<H : Hasher> SuperCell<H> {
+    /// Returns an adapter implementing `Hasher` which forwards to the hasher in this cell
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::{Hash, Hasher};
+    /// use super_cell::SuperCell;
+    /// let state = SuperCell::new(DefaultHasher::new());
+    /// "visited".hash(&mut state.hasher());
+    /// let mut direct = DefaultHasher::new();
+    /// "visited".hash(&mut direct);
+    /// assert_eq!(state.finish_value(), direct.finish());
+    /// ```
+    #[inline]
+    pub fn hasher(&self) -> CellHasher<'_, H> {
+        CellHasher { cell : self }
+    }
+
+    /// Writes `bytes` into the hasher in this cell
+    #[inline]
+    pub fn write(&self, bytes : &[u8]) {
+        self.get_mut().write(bytes)
+    }
+
+    /// Writes a `u64` into the hasher in this cell
+    #[inline]
+    pub fn write_u64(&self, value : u64) {
+        self.get_mut().write_u64(value)
+    }
+
+    /// Returns the hash value for the values written so far
+    #[inline]
+    pub fn finish_value(&self) -> u64 {
+        self.get().finish()
+    }
+}
+
+impl<H : Hasher> Hasher for CellHasher<'_, H> {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.cell.get().finish()
+    }
+
+    #[inline]
+    fn write(&mut self, bytes : &[u8]) {
+        self.cell.get_mut().write(bytes)
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i : u8) {
+        self.cell.get_mut().write_u8(i)
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i : u16) {
+        self.cell.get_mut().write_u16(i)
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i : u32) {
+        self.cell.get_mut().write_u32(i)
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i : u64) {
+        self.cell.get_mut().write_u64(i)
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i : u128) {
+        self.cell.get_mut().write_u128(i)
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i : usize) {
+        self.cell.get_mut().write_usize(i)
+    }
+
+    #[inline]
+    fn write_i8(&mut self, i : i8) {
+        self.cell.get_mut().write_i8(i)
+    }
+
+    #[inline]
+    fn write_i16(&mut self, i : i16) {
+        self.cell.get_mut().write_i16(i)
+    }
+
+    #[inline]
+    fn write_i32(&mut self, i : i32) {
+        self.cell.get_mut().write_i32(i)
+    }
+
+    #[inline]
+    fn write_i64(&mut self, i : i64) {
+        self.cell.get_mut().write_i64(i)
+    }
+
+    #[inline]
+    fn write_i128(&mut self, i : i128) {
+        self.cell.get_mut().write_i128(i)
+    }
+
+    #[inline]
+    fn write_isize(&mut self, i : isize) {
+        self.cell.get_mut().write_isize(i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hash;
+    use super::*;
+
+    struct Context<'a> {
+        state : &'a SuperCell<DefaultHasher>
+    }
+
+    fn visit_name(context : &Context, name : &str) {
+        name.hash(&mut context.state.hasher());
+    }
+
+    fn visit_id(context : &Context, id : u64) {
+        context.state.write_u64(id);
+    }
+
+    #[test]
+    fn matches_direct_hashing() {
+        let state = SuperCell::new(DefaultHasher::new());
+        state.write(b"some bytes");
+        let mut direct = DefaultHasher::new();
+        direct.write(b"some bytes");
+        assert_eq!(state.finish_value(), direct.finish());
+        assert_eq!(state.hasher().finish(), direct.finish());
+    }
+
+    #[test]
+    fn shared_between_call_sites() {
+        let state = SuperCell::new(DefaultHasher::new());
+        let context = Context { state : &state };
+        visit_name(&context, "root");
+        visit_id(&context, 7);
+        visit_name(&context, "leaf");
+
+        let mut direct = DefaultHasher::new();
+        "root".hash(&mut direct);
+        direct.write_u64(7);
+        "leaf".hash(&mut direct);
+        assert_eq!(state.finish_value(), direct.finish());
+    }
+}
@@ -0,0 +1,230 @@
+use core::fmt;
+use core::slice;
+use heapless::{CapacityError, String, Vec};
+use crate::SuperCell;
+
+/// `heapless::Vec` operations, which report a full list with the `Err` of the heapless API
+/// instead of panicking
+///
+/// # Examples
+///
+/// ```
+/// use super_cell::SuperCell;
+/// let samples = SuperCell::new(heapless::Vec::<u16, 2>::new());
+/// samples.push(1).unwrap();
+/// samples.push(2).unwrap();
+/// assert_eq!(samples.push(3), Err(3));
+/// samples.as_slice_of_cells()[0].set(10);
+/// assert_eq!(*samples.get(), [10, 2]);
+/// ```
+impl<T, const N : usize> SuperCell<Vec<T, N>> {
+    /// Returns a slice of cells over the elements of the `heapless::Vec` in this cell
+    ///
+    /// The elements are stored in the list itself, so the pointer is taken through `as_mut_ptr`
+    /// to allow writing through the cells. That also ends any earlier view, so only the latest
+    /// one may be used, and removing elements invalidates it too.
+    #[inline]
+    pub fn as_slice_of_cells(&self) -> &[SuperCell<T>] {
+        let list = self.get_mut();
+        // SAFETY: `SuperCell<T>` has the same memory layout as `T`, the pointer allows writes, and
+        // the elements live as long as the cell.
+        unsafe { slice::from_raw_parts(list.as_mut_ptr().cast::<SuperCell<T>>(), list.len()) }
+    }
+
+    /// Appends `value` to the back of the list, or returns it if the list is full
+    #[inline]
+    pub fn push(&self, value : T) -> Result<(), T> {
+        self.get_mut().push(value)
+    }
+
+    /// Removes the last element and returns it
+    #[inline]
+    pub fn pop(&self) -> Option<T> {
+        self.get_mut().pop()
+    }
+
+    /// Removes every element
+    #[inline]
+    pub fn clear(&self) {
+        self.get_mut().clear()
+    }
+
+    /// Inserts `value` at `index`, shifting every element after it to the right, or returns it if
+    /// the list is full
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    #[inline]
+    pub fn insert(&self, index : usize, value : T) -> Result<(), T> {
+        self.get_mut().insert(index, value)
+    }
+
+    /// Removes the element at `index` and returns it, shifting every element after it to the left
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn remove(&self, index : usize) -> T {
+        self.get_mut().remove(index)
+    }
+
+    /// Removes the element at `index` and returns it, moving the last element into its place
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn swap_remove(&self, index : usize) -> T {
+        self.get_mut().swap_remove(index)
+    }
+
+    /// Shortens the list to `len` elements, dropping the rest
+    #[inline]
+    pub fn truncate(&self, len : usize) {
+        self.get_mut().truncate(len)
+    }
+
+    /// Returns the number of elements in the list
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.get().len()
+    }
+
+    /// Returns `true` if the list has no elements
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.get().is_empty()
+    }
+
+    /// Returns `true` if the list holds `N` elements
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.get().is_full()
+    }
+}
+
+impl<'a, T, const N : usize> IntoIterator for &'a SuperCell<Vec<T, N>> {
+    type Item = &'a SuperCell<T>;
+    type IntoIter = slice::Iter<'a, SuperCell<T>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice_of_cells().iter()
+    }
+}
+
+/// `heapless::String` operations, which report a full string with [`CapacityError`] instead of
+/// panicking
+impl<const N : usize> SuperCell<String<N>> {
+    /// Appends `s` to the end of the string, or leaves it unchanged if `s` does not fit
+    #[inline]
+    pub fn push_str(&self, s : &str) -> Result<(), CapacityError> {
+        self.get_mut().push_str(s)
+    }
+
+    /// Appends `c` to the end of the string, or leaves it unchanged if `c` does not fit
+    #[inline]
+    pub fn push(&self, c : char) -> Result<(), CapacityError> {
+        self.get_mut().push(c)
+    }
+
+    /// Removes the last character and returns it
+    #[inline]
+    pub fn pop(&self) -> Option<char> {
+        self.get_mut().pop()
+    }
+
+    /// Shortens the string to `len` bytes
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` does not lie on a `char` boundary.
+    #[inline]
+    pub fn truncate(&self, len : usize) {
+        self.get_mut().truncate(len)
+    }
+
+    /// Clears the string
+    #[inline]
+    pub fn clear(&self) {
+        self.get_mut().clear()
+    }
+
+    /// Returns the length of the string in bytes
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.get().len()
+    }
+
+    /// Returns `true` if the string is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.get().is_empty()
+    }
+}
+
+/// Fails with `fmt::Error` once the string is full, keeping what was written before
+impl<const N : usize> fmt::Write for &SuperCell<String<N>> {
+    #[inline]
+    fn write_str(&mut self, s : &str) -> fmt::Result {
+        self.push_str(s).map_err(|_| fmt::Error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Write;
+    use super::*;
+
+    #[test]
+    fn vec_to_capacity() {
+        let list = SuperCell::new(Vec::<u32, 4>::new());
+        for value in 0..4 {
+            assert_eq!(list.push(value), Ok(()));
+        }
+        assert!(list.is_full());
+        assert_eq!(list.push(4), Err(4));
+        assert_eq!(list.insert(0, 5), Err(5));
+        assert_eq!(*list.get(), [0, 1, 2, 3]);
+
+        for cell in &list {
+            *cell.get_mut() *= 10;
+        }
+        list.as_slice_of_cells()[3].set(7);
+        assert_eq!(*list.get(), [0, 10, 20, 7]);
+
+        assert_eq!(list.swap_remove(0), 0);
+        assert_eq!(list.remove(0), 7);
+        assert_eq!(list.insert(1, 15), Ok(()));
+        assert_eq!(*list.get(), [10, 15, 20]);
+        assert_eq!(list.pop(), Some(20));
+        list.truncate(1);
+        assert_eq!(list.len(), 1);
+        list.clear();
+        assert!(list.is_empty());
+        assert_eq!(list.as_slice_of_cells().len(), 0);
+    }
+
+    #[test]
+    fn string_to_capacity() {
+        let text = SuperCell::new(String::<8>::new());
+        assert!(text.push_str("abcdef").is_ok());
+        assert!(text.push_str("ghi").is_err());
+        assert!(text.push('g').is_ok());
+        // Two bytes left would be needed for `é`, only one is.
+        assert!(text.push('é').is_err());
+        assert_eq!(text.get().as_str(), "abcdefg");
+        assert_eq!(text.pop(), Some('g'));
+        text.truncate(2);
+        assert_eq!(text.len(), 2);
+
+        let mut writer = &text;
+        assert!(write!(writer, "{}", 1234).is_ok());
+        assert!(write!(writer, "{}", 56789).is_err());
+        assert_eq!(text.get().as_str(), "ab1234");
+        text.clear();
+        assert!(text.is_empty());
+    }
+}
@@ -0,0 +1,130 @@
+use core::mem::ManuallyDrop;
+use core::ptr;
+use crate::SuperCell;
+
+/// `SuperCell` which hands every displaced value to a hook, for values owning external
+/// resources that must be released explicitly
+///
+/// The hook is called with the old value by [`set`](Self::set), [`replace_with`](Self::replace_with)
+/// and [`take`](Self::take), and with the final value when the cell is dropped. Only
+/// [`into_inner`](Self::into_inner) bypasses it.
+///
+/// The hook runs while the cell is in the middle of an update, so it must not access the cell it
+/// belongs to. Doing so creates a second mutable reference to the hook, which is undefined
+/// behavior like any other overlapping `get_mut`.
+///
+/// # Examples
+///
+/// ```
+/// use super_cell::{DropHookCell, SuperCell};
+/// let released = SuperCell::new(Vec::new());
+/// let handle = DropHookCell::new(1, |handle| released.get_mut().push(handle));
+/// handle.set(2);
+/// handle.replace_with(|handle| *handle + 1);
+/// drop(handle);
+/// assert_eq!(*released.get(), [1, 2, 3]);
+/// ```
+pub struct DropHookCell<T, F : FnMut(T)> {
+    value : SuperCell<ManuallyDrop<T>>,
+    hook : SuperCell<F>
+}
+
+impl<T, F : FnMut(T)> DropHookCell<T, F> {
+    #[inline]
+    pub const fn new(value : T, on_drop : F) -> Self {
+        Self {
+            value : SuperCell::new(ManuallyDrop::new(value)),
+            hook : SuperCell::new(on_drop)
+        }
+    }
+
+    /// Returns a shared reference to the value
+    #[inline]
+    pub fn get(&self) -> &T {
+        self.value.get()
+    }
+
+    /// Returns a mutable reference to the value
+    #[inline]
+    #[track_caller]
+    #[allow(clippy::mut_from_ref)]
+    pub fn get_mut(&self) -> &mut T {
+        self.value.get_mut()
+    }
+
+    /// Sets the value, handing the old one to the hook
+    #[inline]
+    #[track_caller]
+    pub fn set(&self, value : T) {
+        let previous = self.value.replace(ManuallyDrop::new(value));
+        (self.hook.get_mut())(ManuallyDrop::into_inner(previous));
+    }
+
+    /// Sets the value computed by `f` from the old one, then hands the old one to the hook
+    #[inline]
+    #[track_caller]
+    pub fn replace_with(&self, f : impl FnOnce(&mut T) -> T) {
+        let value = f(self.get_mut());
+        self.set(value);
+    }
+
+    /// Takes the value, leaving `Default::default()` and handing the old value to the hook
+    #[inline]
+    #[track_caller]
+    pub fn take(&self) where T : Default {
+        self.set(T::default());
+    }
+
+    /// Consumes the cell, returning the value and the hook without calling it
+    pub fn into_inner(self) -> (T, F) {
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `this` is never dropped, so each field is moved out exactly once.
+        unsafe {
+            let value = ptr::read(&this.value).into_inner();
+            (ManuallyDrop::into_inner(value), ptr::read(&this.hook).into_inner())
+        }
+    }
+}
+
+impl<T, F : FnMut(T)> Drop for DropHookCell<T, F> {
+    fn drop(&mut self) {
+        // SAFETY: The value is never accessed again, and its `ManuallyDrop` is not dropped.
+        let value = unsafe { ManuallyDrop::take(self.value.get_mut()) };
+        (self.hook.get_mut())(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+    use super::*;
+
+    #[test]
+    fn hook_sees_every_value() {
+        let released = SuperCell::new(Vec::new());
+        let cell = DropHookCell::new(String::from("a"), |value| released.get_mut().push(value));
+        cell.set(String::from("b"));
+        cell.get_mut().push('!');
+        cell.replace_with(|value| format!("{value}c"));
+        cell.take();
+        assert_eq!(cell.get(), "");
+        cell.set(String::from("d"));
+        assert_eq!(released.get().len(), 4);
+        drop(cell);
+        assert_eq!(*released.get(), ["a", "b!", "b!c", "", "d"]);
+    }
+
+    #[test]
+    fn into_inner_bypasses_hook() {
+        let calls = SuperCell::new(0);
+        let tracked = Rc::new(());
+        let cell = DropHookCell::new(Rc::clone(&tracked), |_| *calls.get_mut() += 1);
+        cell.set(Rc::clone(&tracked));
+        let (value, mut hook) = cell.into_inner();
+        assert_eq!(*calls.get(), 1);
+        assert_eq!(Rc::strong_count(&tracked), 2);
+        hook(value);
+        assert_eq!(*calls.get(), 2);
+        assert_eq!(Rc::strong_count(&tracked), 1);
+    }
+}
@@ -0,0 +1,149 @@
+use core::cmp::Ordering;
+use core::fmt::{Debug, Formatter};
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+use crate::SuperCell;
+
+impl<T : ?Sized> SuperCell<T> {
+    /// Returns `true` if both references point to the same cell, unlike `==` which compares the
+    /// values
+    ///
+    /// Only the addresses are compared, the metadata of unsized cells is ignored. Distinct zero
+    /// sized cells may share an address and compare as the same cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let (first, second) = (SuperCell::new(1), SuperCell::new(1));
+    /// assert!(first == second);
+    /// assert!(!SuperCell::ptr_eq(&first, &second));
+    /// assert!(SuperCell::ptr_eq(&first, &first));
+    /// ```
+    #[inline]
+    pub fn ptr_eq(this : &SuperCell<T>, other : &SuperCell<T>) -> bool {
+        core::ptr::addr_eq(this.as_ptr(), other.as_ptr())
+    }
+}
+
+/// Reference to a cell which compares, orders and hashes by the address of the cell instead of
+/// its value
+///
+/// For sets and maps keyed by cell identity, like visited sets when walking graphs of cells.
+/// Only the thin address is used, so unsized cells hash the same whatever their metadata. The
+/// value never affects the key, which makes `clippy::mutable_key_type` a false positive here.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+/// use super_cell::{ByAddress, SuperCell};
+/// let nodes = [SuperCell::new("a"), SuperCell::new("a")];
+/// let mut visited = HashSet::new();
+/// for node in &nodes {
+///     assert!(visited.insert(ByAddress(node)));
+/// }
+/// assert!(visited.contains(&ByAddress(&nodes[0])));
+/// ```
+pub struct ByAddress<'a, T : ?Sized>(pub &'a SuperCell<T>);
+
+impl<T : ?Sized> ByAddress<'_, T> {
+    #[inline]
+    fn address(&self) -> usize {
+        self.0.as_ptr() as *const () as usize
+    }
+}
+
+impl<T : ?Sized> Deref for ByAddress<'_, T> {
+    type Target = SuperCell<T>;
+
+    #[inline]
+    fn deref(&self) -> &SuperCell<T> {
+        self.0
+    }
+}
+
+impl<T : ?Sized> Clone for ByAddress<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T : ?Sized> Copy for ByAddress<'_, T> {}
+
+impl<T : ?Sized> PartialEq for ByAddress<'_, T> {
+    #[inline]
+    fn eq(&self, other : &Self) -> bool {
+        SuperCell::ptr_eq(self.0, other.0)
+    }
+}
+
+impl<T : ?Sized> Eq for ByAddress<'_, T> {}
+
+impl<T : ?Sized> PartialOrd for ByAddress<'_, T> {
+    #[inline]
+    fn partial_cmp(&self, other : &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T : ?Sized> Ord for ByAddress<'_, T> {
+    #[inline]
+    fn cmp(&self, other : &Self) -> Ordering {
+        self.address().cmp(&other.address())
+    }
+}
+
+impl<T : ?Sized> Hash for ByAddress<'_, T> {
+    #[inline]
+    fn hash<H : Hasher>(&self, state : &mut H) {
+        self.address().hash(state)
+    }
+}
+
+impl<T : Debug + ?Sized> Debug for ByAddress<'_, T> {
+    fn fmt(&self, f : &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ByAddress").field(&self.0.as_ptr()).field(&self.0.get()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashSet};
+    use super::*;
+
+    #[test]
+    #[allow(clippy::mutable_key_type)]
+    fn equal_values_distinct_cells() {
+        let nodes = [SuperCell::new(7), SuperCell::new(7), SuperCell::new(8)];
+        let mut visited = HashSet::new();
+        for node in &nodes {
+            assert!(visited.insert(ByAddress(node)));
+        }
+        assert_eq!(visited.len(), 3);
+        for node in &nodes {
+            assert!(!visited.insert(ByAddress(node)));
+            assert!(visited.contains(&ByAddress(node)));
+        }
+        let other = SuperCell::new(7);
+        assert!(!visited.contains(&ByAddress(&other)));
+
+        let ordered : BTreeSet<_> = nodes.iter().map(ByAddress).collect();
+        assert_eq!(ordered.len(), 3);
+        assert!(ordered.iter().map(|node| node.as_ptr() as usize).is_sorted());
+    }
+
+    #[test]
+    #[allow(clippy::mutable_key_type)]
+    fn unsized_by_thin_address() {
+        let mut values = [1, 2, 3];
+        let cells = SuperCell::from_mut_slice(&mut values);
+        let whole = cells.as_slice_of_cells();
+        let (front, back) = (SuperCell::from_slice_of_cells(&whole[..1]), SuperCell::from_slice_of_cells(&whole[1..]));
+        assert!(SuperCell::ptr_eq(cells, front));
+        assert!(!SuperCell::ptr_eq(cells, back));
+        let set = HashSet::from([ByAddress(cells)]);
+        assert!(set.contains(&ByAddress(front)));
+        assert_eq!(*ByAddress(back).get(), [2, 3]);
+    }
+}
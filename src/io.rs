@@ -0,0 +1,172 @@
+use std::io::{self, Cursor, Read, Seek, SeekFrom, Write};
+use crate::SuperCell;
+
+/// Adapter returned by [`SuperCell::writer`] implementing `io::Write` by appending to a
+/// `SuperCell<Vec<u8>>`
+#[derive(Clone, Copy)]
+pub struct CellWriter<'a> {
+    cell : &'a SuperCell<Vec<u8>>
+}
+
+/// Adapter returned by [`SuperCell::reader`] implementing `io::Read` and `io::Seek` over the
+/// bytes in a cell
+///
+/// The adapter keeps its own cursor position, so several readers over the same cell are
+/// independent of each other.
+pub struct CellReader<'a, B : ?Sized> {
+    cell : &'a SuperCell<B>,
+    position : u64
+}
+
+impl SuperCell<Vec<u8>> {
+    /// Returns an adapter implementing `io::Write` which appends to the bytes in this cell
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Write;
+    /// use super_cell::SuperCell;
+    /// let buffer = SuperCell::new(vec![]);
+    /// buffer.writer().write_all(b"hello").unwrap();
+    /// assert_eq!(buffer.get(), b"hello");
+    /// ```
+    #[inline]
+    pub fn writer(&self) -> CellWriter<'_> {
+        CellWriter { cell : self }
+    }
+
+    /// Returns an adapter implementing `io::Read` and `io::Seek` over the bytes in this cell
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Read;
+    /// use super_cell::SuperCell;
+    /// let buffer = SuperCell::new(b"hello".to_vec());
+    /// let mut text = String::new();
+    /// buffer.reader().read_to_string(&mut text).unwrap();
+    /// assert_eq!(text, "hello");
+    /// ```
+    #[inline]
+    pub fn reader(&self) -> CellReader<'_, Vec<u8>> {
+        CellReader { cell : self, position : 0 }
+    }
+}
+
+impl<'b> SuperCell<&'b [u8]> {
+    /// Returns an adapter implementing `io::Read` and `io::Seek` over the bytes in this cell
+    #[inline]
+    pub fn reader(&self) -> CellReader<'_, &'b [u8]> {
+        CellReader { cell : self, position : 0 }
+    }
+}
+
+impl Write for CellWriter<'_> {
+    #[inline]
+    fn write(&mut self, buf : &[u8]) -> io::Result<usize> {
+        self.cell.get_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf : &[u8]) -> io::Result<()> {
+        self.cell.get_mut().extend_from_slice(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<B : ?Sized> Clone for CellReader<'_, B> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<B : ?Sized> Copy for CellReader<'_, B> {}
+
+impl<B : AsRef<[u8]> + ?Sized> CellReader<'_, B> {
+    /// Returns the current position of this reader
+    #[inline]
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    fn with_cursor<R>(&mut self, f : impl FnOnce(&mut Cursor<&[u8]>) -> R) -> R {
+        let mut cursor = Cursor::new(self.cell.get().as_ref());
+        cursor.set_position(self.position);
+        let result = f(&mut cursor);
+        self.position = cursor.position();
+        result
+    }
+}
+
+impl<B : AsRef<[u8]> + ?Sized> Read for CellReader<'_, B> {
+    #[inline]
+    fn read(&mut self, buf : &mut [u8]) -> io::Result<usize> {
+        self.with_cursor(|cursor| cursor.read(buf))
+    }
+}
+
+impl<B : AsRef<[u8]> + ?Sized> Seek for CellReader<'_, B> {
+    #[inline]
+    fn seek(&mut self, position : SeekFrom) -> io::Result<u64> {
+        self.with_cursor(|cursor| cursor.seek(position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn copy_between_cells() {
+        let source = SuperCell::new((0..=255u8).cycle().take(10_000).collect::<Vec<_>>());
+        let destination = SuperCell::new(b"header:".to_vec());
+        let copied = io::copy(&mut source.reader(), &mut destination.writer()).unwrap();
+        assert_eq!(copied, 10_000);
+        assert_eq!(&destination.get()[..7], b"header:");
+        assert_eq!(&destination.get()[7..], source.get().as_slice());
+    }
+
+    #[test]
+    fn large_writes_are_not_split() {
+        let destination = SuperCell::new(vec![]);
+        let data = vec![7u8; 1 << 20];
+        assert_eq!(destination.writer().write(&data).unwrap(), data.len());
+        destination.writer().flush().unwrap();
+        assert_eq!(*destination.get(), data);
+    }
+
+    #[test]
+    fn seek_and_read() {
+        let source = SuperCell::new(&b"0123456789"[..]);
+        let mut reader = source.reader();
+        let mut buf = [0; 3];
+        reader.seek(SeekFrom::Start(4)).unwrap();
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"456");
+        assert_eq!(reader.seek(SeekFrom::End(-2)).unwrap(), 8);
+        assert_eq!(reader.read(&mut buf).unwrap(), 2);
+        assert_eq!(&buf[..2], b"89");
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+        assert!(reader.seek(SeekFrom::Current(-20)).is_err());
+        assert_eq!(reader.position(), 10);
+    }
+
+    #[test]
+    fn copied_readers_are_independent() {
+        let source = SuperCell::new(b"0123".to_vec());
+        let mut first = source.reader();
+        let mut second = first;
+        let mut buf = [0; 2];
+        first.read_exact(&mut buf).unwrap();
+        second.read_exact(&mut buf).unwrap();
+        second.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"23");
+        assert_eq!((first.position(), second.position()), (2, 4));
+    }
+}
@@ -0,0 +1,98 @@
+use crate::SuperCell;
+
+/// Iterator returned by [`SuperCell::iter_by_ref`], pulling items from the iterator in a cell
+pub struct IterByRef<'a, I> {
+    cell : &'a SuperCell<I>
+}
+
+impl<I : Iterator> SuperCell<I> {
+    /// Advances the inner iterator and returns the next item
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let tasks = SuperCell::new(vec![1, 2, 3].into_iter());
+    /// assert_eq!(tasks.next_item(), Some(1));
+    /// assert_eq!(tasks.iter_by_ref().collect::<Vec<_>>(), vec![2, 3]);
+    /// ```
+    #[inline]
+    pub fn next_item(&self) -> Option<I::Item> {
+        self.get_mut().next()
+    }
+
+    /// Advances the inner iterator by `n` items, returning `Err(k)` if it ran out with `k`
+    /// steps left to go
+    pub fn advance_by(&self, n : usize) -> Result<(), usize> {
+        let iterator = self.get_mut();
+        for step in 0..n {
+            if iterator.next().is_none() {
+                return Err(n - step);
+            }
+        }
+        Ok(())
+    }
+
+    /// Calls `f` on every remaining item of the inner iterator
+    #[inline]
+    pub fn for_each_remaining(&self, f : impl FnMut(I::Item)) {
+        self.get_mut().for_each(f)
+    }
+
+    /// Returns an iterator pulling items from the inner iterator, usable in `for` loops and
+    /// with iterator combinators
+    #[inline]
+    pub fn iter_by_ref(&self) -> IterByRef<'_, I> {
+        IterByRef { cell : self }
+    }
+}
+
+impl<I : Iterator> Iterator for IterByRef<'_, I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        self.cell.next_item()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.cell.get().size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interleaved_pulls() {
+        let tasks = SuperCell::new((0..100).collect::<Vec<usize>>().into_iter());
+        let mut first = vec![];
+        let mut second = vec![];
+        let mut pulls = tasks.iter_by_ref();
+        while let Some(task) = tasks.next_item() {
+            first.push(task);
+            second.extend(pulls.by_ref().take(2));
+        }
+        assert_eq!(first.len() + second.len(), 100);
+        let mut all : Vec<_> = first.into_iter().chain(second).collect();
+        all.sort_unstable();
+        assert_eq!(all, (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn advance_and_drain() {
+        let tasks = SuperCell::new(0..10);
+        assert_eq!(tasks.advance_by(3), Ok(()));
+        assert_eq!(tasks.next_item(), Some(3));
+        assert_eq!(tasks.iter_by_ref().size_hint(), (6, Some(6)));
+        let mut rest = vec![];
+        tasks.for_each_remaining(|task| rest.push(task));
+        assert_eq!(rest, vec![4, 5, 6, 7, 8, 9]);
+        assert_eq!(tasks.advance_by(2), Err(2));
+
+        let tasks = SuperCell::new(0..3);
+        assert_eq!(tasks.advance_by(5), Err(2));
+    }
+}
@@ -0,0 +1,105 @@
+use crate::SuperCell;
+
+/// Wrapper returned by [`SuperCell::labeled`] which counts accesses under a label
+///
+/// With the `stats` feature, reads and writes through the wrapper are added to the counters
+/// reported by `stats::report`. Without it the label is dropped and the wrapper is a plain
+/// reference to the cell.
+pub struct LabeledCell<'a, T : ?Sized> {
+    cell : &'a SuperCell<T>,
+    #[cfg(feature = "stats")]
+    counters : &'static crate::stats::Counters
+}
+
+impl<T : ?Sized> SuperCell<T> {
+    /// Returns a wrapper counting accesses to this cell under `label`
+    ///
+    /// Several wrappers, over the same or different cells, can share a label and add to the
+    /// same counters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let positions = SuperCell::new(vec![0.0f32; 4]);
+    /// let labeled = positions.labeled("physics.positions");
+    /// labeled.get_mut()[0] = 1.0;
+    /// assert_eq!(labeled.get()[0], 1.0);
+    /// ```
+    #[inline]
+    pub fn labeled(&self, label : &str) -> LabeledCell<'_, T> {
+        #[cfg(not(feature = "stats"))]
+        let _ = label;
+        LabeledCell {
+            cell : self,
+            #[cfg(feature = "stats")]
+            counters : crate::stats::counters(label)
+        }
+    }
+}
+
+impl<'a, T : ?Sized> LabeledCell<'a, T> {
+    /// Returns the wrapped cell, accesses through it are not counted
+    #[inline]
+    pub fn cell(&self) -> &'a SuperCell<T> {
+        self.cell
+    }
+
+    /// Counts a read and returns a shared reference to the value
+    #[inline]
+    pub fn get(&self) -> &'a T {
+        #[cfg(feature = "stats")]
+        self.counters.read();
+        self.cell.get()
+    }
+
+    /// Counts a write and returns a mutable reference to the value
+    #[inline]
+    #[track_caller]
+    #[allow(clippy::mut_from_ref)]
+    pub fn get_mut(&self) -> &'a mut T {
+        #[cfg(feature = "stats")]
+        self.counters.write();
+        self.cell.get_mut()
+    }
+}
+
+impl<T> LabeledCell<'_, T> {
+    /// Counts a write and sets the value
+    #[inline]
+    #[track_caller]
+    pub fn set(&self, value : T) {
+        #[cfg(feature = "stats")]
+        self.counters.write();
+        self.cell.set(value)
+    }
+}
+
+impl<T : ?Sized> Clone for LabeledCell<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T : ?Sized> Copy for LabeledCell<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "stats"))]
+    #[test]
+    fn zero_overhead() {
+        assert_eq!(size_of::<LabeledCell<'_, [u8]>>(), size_of::<&[u8]>());
+    }
+
+    #[test]
+    fn forwards_accesses() {
+        let cell = SuperCell::new(1);
+        let labeled = cell.labeled("labeled.forwards");
+        labeled.set(2);
+        *labeled.get_mut() += 1;
+        assert_eq!(*labeled.get(), 3);
+        assert!(core::ptr::eq(labeled.cell(), &cell));
+    }
+}
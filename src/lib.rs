@@ -1,11 +1,158 @@
-use std::cell::UnsafeCell;
-use std::cmp::Ordering;
-use std::fmt::{Debug, Display, Formatter};
-use std::hash::{Hash, Hasher};
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(feature = "fn_traits", feature(fn_traits, unboxed_closures, tuple_trait))]
+// `get_mut` is only deprecated for users of `strict`, the crate still builds on it internally
+#![cfg_attr(feature = "strict", allow(deprecated))]
+
+extern crate alloc;
+
+use core::cell::UnsafeCell;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+mod aligned;
+mod borrow;
+mod branded;
+mod boxed;
+#[cfg(feature = "bytes")]
+mod bytes;
+mod atomic;
+mod call;
+mod cast;
+mod convert;
+#[cfg(feature = "critical-section")]
+mod critical;
+mod deque;
+pub mod error;
+mod eq;
+mod fields;
+mod fold;
+mod frozen;
+mod future;
+#[cfg(feature = "graph")]
+pub mod graph;
+mod hash;
+#[cfg(feature = "heapless")]
+mod heapless;
+mod hook;
+mod identity;
+#[cfg(feature = "std")]
+mod io;
+mod iter;
+mod labeled;
+#[cfg(feature = "std")]
+mod local;
+#[cfg(all(feature = "lock_api", target_has_atomic = "ptr"))]
+mod lock;
+mod location;
+#[cfg(all(target_has_atomic = "8", target_has_atomic = "ptr"))]
+pub mod mailbox;
+#[cfg(feature = "std")]
+mod map;
+mod manual;
+mod niche;
+#[cfg(feature = "std")]
+mod par;
+#[cfg(feature = "rayon")]
+mod rayon;
+mod read_only;
+mod result;
+mod scoped;
+#[cfg(feature = "zeroize")]
+mod secret;
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(target_has_atomic = "ptr")]
+mod shared;
+#[cfg(feature = "simd")]
+mod simd;
+mod slice;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+#[cfg(feature = "stats")]
+pub mod stats;
+mod string;
+#[cfg(feature = "trace")]
+mod trace;
+#[cfg(feature = "std")]
+mod token;
+mod tsan;
+mod tuple;
+mod vec;
+mod weak;
+
+pub use aligned::{AlignedCell, Alignment, SupportedAlignment};
+pub use borrow::{CellRef, CellRefMut};
+pub use error::{BorrowError, BorrowMutError};
+pub use branded::{BrandedCell, CellOwner};
+#[cfg(feature = "bytes")]
+pub use bytes::{CellBuf, CellBufMut};
+pub use read_only::ReadOnlyCell;
+pub use scoped::ScopedOverride;
+#[cfg(feature = "zeroize")]
+pub use secret::SecretCell;
+#[cfg(feature = "critical-section")]
+pub use critical::CriticalCell;
+pub use fields::CellProject;
+pub use frozen::FrozenCell;
+pub use hash::CellHasher;
+pub use hook::DropHookCell;
+pub use identity::ByAddress;
+#[cfg(feature = "std")]
+pub use io::{CellReader, CellWriter};
+pub use iter::IterByRef;
+pub use labeled::LabeledCell;
+pub use manual::ManualCell;
+pub use niche::{NicheCell, NonZeroPrimitive};
+#[cfg(all(target_has_atomic = "8", target_has_atomic = "ptr"))]
+pub use mailbox::MailboxCell;
+#[cfg(all(feature = "lock_api", target_has_atomic = "ptr"))]
+pub use lock::{RawSuperLock, RawSuperRwLock, SuperMutex, SuperRwLock};
+#[cfg(target_has_atomic = "ptr")]
+pub use shared::SharedSuperCell;
+pub use string::StringWriter;
+#[cfg(feature = "derive")]
+pub use super_cell_derive::{cellify, CellProject};
+#[cfg(feature = "std")]
+pub use token::{Token, TokenCell};
+pub use vec::CollectCells;
+
 /// Modified version of `Cell`
+///
+/// `SuperCell<T>` is guaranteed to have exactly the same size, alignment and ABI as `T`, so it
+/// can be used in place of `T` in `#[repr(C)]` structs shared with C code.
+///
+/// # Aliasing model
+///
+/// Every reference handed out by the cell is derived fresh from the `UnsafeCell` pointer, never
+/// from a reference that was returned earlier. Holding several references at once is fine, what
+/// matters is how they are used:
+///
+/// - A `&mut T` from [`SuperCell::get_mut`] must not be used while another reference into the
+///   same value is read or written. Using it, then using a different one, is sound. Interleaving
+///   uses of two overlapping references is undefined behaviour, even on a single thread.
+/// - References into disjoint parts of the value, such as fields from [`project!`] or elements
+///   from [`SuperCell::as_slice_of_cells`], never overlap and can be used freely.
+/// - Accesses from different threads must be ordered by something else, like a join, a channel
+///   or a lock. The cell does not synchronize anything.
+///
+/// Patterns the reference API cannot make sound should use [`SuperCell::with`] and
+/// [`SuperCell::with_mut`], which keep the borrow inside a closure, [`SuperCell::set`] and
+/// [`SuperCell::replace`], which never hand out a reference, or [`SuperCell::as_ptr`] with raw
+/// pointer reads and writes.
+///
+/// # Serialization
+///
+/// With the `serde` feature, cells serialize as their logical value and never add a layer to the
+/// wire format. `SuperCell<T>`, [`FrozenCell<T>`], [`AlignedCell<T, N>`] and `CriticalCell<T>` are
+/// written as `T`, and [`ManualCell<T>`] and [`NicheCell<T>`] as `Option<T>`, `None` being an empty
+/// slot. The views [`ReadOnlyCell`] and [`LabeledCell`] only serialize. Everything else the cell
+/// tracks, like the alignment, is restored by the type on deserialization. Types whose value cannot
+/// be reached or rebuilt from data alone do not implement serde at all: the branded and token cells
+/// need their owner, [`DropHookCell`] its hook, `MailboxCell` would race its senders, and
+/// `SecretCell` stays out of serialized data on purpose.
 #[repr(transparent)]
 pub struct SuperCell<T : ?Sized> {
     value : UnsafeCell<T>
@@ -19,15 +166,334 @@ impl <T> SuperCell<T> {
         }
     }
 
+    /// Consumes the cell, returning the wrapped value
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+
+    /// Sets the contained value, dropping the previous one
+    #[inline]
+    #[track_caller]
+    pub fn set(&self, value : T) {
+        #[cfg(feature = "trace")]
+        let _trace = trace::Write::begin(self, "set");
+        *self.borrow_mut() = value;
+    }
+
+    /// Replaces the contained value with `value`, returning the old one
+    #[inline]
+    #[track_caller]
+    pub fn replace(&self, value : T) -> T {
+        #[cfg(feature = "trace")]
+        let _trace = trace::Write::begin(self, "replace");
+        core::mem::replace(&mut self.borrow_mut(), value)
+    }
+
+    /// Reads the value with `ptr::read_volatile`, for memory mapped registers and the like
+    ///
+    /// Volatile accesses are not a synchronization mechanism, a volatile read racing with a
+    /// write from another thread is still a data race.
+    #[inline]
+    pub fn read_volatile(&self) -> T where T : Copy {
+        // SAFETY: The pointer is valid, aligned and initialized for as long as `self` lives.
+        unsafe { self.as_ptr().read_volatile() }
+    }
+
+    /// Writes the value with `ptr::write_volatile`, dropping nothing
+    ///
+    /// The previous value is overwritten without being dropped, which is what registers want
+    /// but will leak for types with drop glue. Like [`SuperCell::read_volatile`], this does
+    /// not synchronize with other threads.
+    #[inline]
+    #[track_caller]
+    pub fn write_volatile(&self, value : T) {
+        location::record_write(self);
+        #[cfg(feature = "trace")]
+        let _trace = trace::Write::begin(self, "write_volatile");
+        // SAFETY: The pointer is valid and aligned for as long as `self` lives.
+        unsafe { self.as_ptr().write_volatile(value) }
+    }
+
+    /// Takes the value of the cell, leaving `Default::default()` in its place
     #[inline]
+    #[track_caller]
+    pub fn take(&self) -> T where T : Default {
+        self.replace(T::default())
+    }
+
+    /// Builds a new value from the current one and swaps it in only if `f` succeeds,
+    /// returning the old value. On `Err` the cell is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let cell = SuperCell::new(String::from("10"));
+    /// let old = cell.try_replace_with(|text| text.parse::<u32>().map(|value| (value * 2).to_string()));
+    /// assert_eq!(old.as_deref(), Ok("10"));
+    /// assert!(cell.try_replace_with(|text| text.strip_prefix('x').map(String::from).ok_or(())).is_err());
+    /// assert_eq!(cell.get(), "20");
+    /// ```
+    #[track_caller]
+    pub fn try_replace_with<E>(&self, f : impl FnOnce(&T) -> Result<T, E>) -> Result<T, E> {
+        let candidate = f(self.get())?;
+        Ok(self.replace(candidate))
+    }
+}
+
+impl<T : ?Sized> SuperCell<T> {
+    /// Returns a mutable reference to the value
+    ///
+    /// The reference is derived from the cell's pointer, so it does not invalidate the cell, but
+    /// it must not be used while any other reference into the value is in use. See the
+    /// [aliasing model](SuperCell#aliasing-model).
+    ///
+    /// With the `strict` feature this is deprecated in favour of the checked
+    /// [`SuperCell::borrow_mut`].
+    #[inline]
+    #[track_caller]
+    #[allow(clippy::mut_from_ref)]
+    #[cfg_attr(feature = "strict", deprecated(note = "use `borrow_mut`, which checks for overlapping borrows"))]
     pub fn get_mut(&self) -> &mut T {
+        location::record_write(self);
+        // SAFETY: The pointer is valid for as long as `self` lives, non-overlapping use is up
+        // to the caller as documented above.
         unsafe { &mut (*self.value.get()) }
     }
 
+    /// Returns a shared reference to the value
+    ///
+    /// The value must not be written through another reference while this one is in use.
     #[inline]
     pub fn get(&self) -> &T {
+        // SAFETY: See `get_mut`.
         unsafe { &(*self.value.get()) }
     }
+
+    /// Calls `f` with a shared reference to the value, so the borrow cannot escape the closure.
+    ///
+    /// Prefer this and [`SuperCell::with_mut`] over `get`/`get_mut` where possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let cell = SuperCell::new(vec![1, 2, 3]);
+    /// assert_eq!(cell.with(|list| list.len()), 3);
+    /// ```
+    #[inline]
+    pub fn with<R>(&self, f : impl FnOnce(&T) -> R) -> R {
+        f(&self.borrow())
+    }
+
+    /// Calls `f` with a mutable reference to the value, so the borrow cannot escape the closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let cell = SuperCell::new(vec![1, 2, 3]);
+    /// cell.with_mut(|list| list.push(4));
+    /// assert_eq!(*cell.get(), vec![1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn with_mut<R>(&self, f : impl FnOnce(&mut T) -> R) -> R {
+        #[cfg(feature = "trace")]
+        let _trace = trace::Write::begin(self, "with_mut");
+        f(&mut self.borrow_mut())
+    }
+
+    /// Calls a fallible `f` with a mutable reference to the value, propagating its result.
+    ///
+    /// Any changes `f` made before failing are kept, use [`SuperCell::try_replace_with`] to
+    /// only apply changes on success.
+    #[inline]
+    #[track_caller]
+    pub fn try_update<R, E>(&self, f : impl FnOnce(&mut T) -> Result<R, E>) -> Result<R, E> {
+        #[cfg(feature = "trace")]
+        let _trace = trace::Write::begin(self, "try_update");
+        f(&mut self.borrow_mut())
+    }
+
+    /// Returns a `&SuperCell<T>` from a `&mut T`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let mut value = 5;
+    /// let cell = SuperCell::from_mut(&mut value);
+    /// *cell.get_mut() += 1;
+    /// assert_eq!(value, 6);
+    /// ```
+    #[inline]
+    pub fn from_mut(value : &mut T) -> &SuperCell<T> {
+        // SAFETY: `SuperCell<T>` has the same memory layout as `T` and `&mut` guarantees unique access.
+        unsafe { &*(value as *mut T as *const SuperCell<T>) }
+    }
+
+    /// Returns a raw pointer to the underlying data in this cell
+    #[inline]
+    pub const fn as_ptr(&self) -> *mut T {
+        self.value.get()
+    }
+
+    /// Side table key for the checked and traced modes, `None` for zero sized values since
+    /// those all share addresses
+    #[cfg(any(feature = "strict", feature = "trace", all(feature = "debug-trace", debug_assertions)))]
+    fn tracking_key(&self) -> Option<usize> {
+        (size_of_val(self) != 0).then(|| self.as_ptr() as *const () as usize)
+    }
+}
+
+/// Projects a `&SuperCell<T>` onto one of the fields of `T`, giving a `&SuperCell<Field>`
+///
+/// The field path may have several segments and include tuple and array indices. The place is
+/// computed with raw pointers, so no reference to the whole value is ever created and several
+/// fields of the same cell can be projected at the same time.
+///
+/// Paths must only go through fields and array indices. Indexing a `Vec` or going through a
+/// `Deref` impl calls user code with a reference to the container, which defeats the purpose.
+/// Index expressions are evaluated before the projection, outside of its unsafe block, and
+/// fields which may be unaligned, like those of `#[repr(packed)]` structs, fail to compile.
+///
+/// # Examples
+///
+/// ```
+/// use super_cell::{project, SuperCell};
+/// struct Vec3 { x : f32, y : f32, z : f32 }
+/// struct Player { hp : u32, position : Vec3 }
+///
+/// let player = SuperCell::new(Player { hp: 10, position: Vec3 { x: 0.0, y: 0.0, z: 0.0 } });
+/// let hp = project!(&player => hp);
+/// let x = project!(&player => position.x);
+/// *hp.get_mut() -= 1;
+/// *x.get_mut() += 1.5;
+/// assert_eq!(player.get().hp, 9);
+/// assert_eq!(player.get().position.x, 1.5);
+/// ```
+///
+/// ```compile_fail
+/// use super_cell::{project, SuperCell};
+/// #[repr(packed)]
+/// struct Header { kind : u8, len : u32 }
+///
+/// let header = SuperCell::new(Header { kind: 1, len: 0 });
+/// let len = project!(&header => len);
+/// ```
+///
+/// ```compile_fail
+/// use super_cell::{project, SuperCell};
+/// let slots = SuperCell::new([0; 4]);
+/// let index = &1 as *const usize;
+/// let slot = project!(&slots => [*index]);
+/// ```
+#[macro_export]
+macro_rules! project {
+    (@step $cell:ident,) => { $cell };
+    (@step $cell:ident, [$index:expr] $($rest:tt)*) => {
+        $crate::project!(@index $cell, $index; $($rest)*)
+    };
+    (@step $cell:ident, . $field:tt $($rest:tt)*) => {
+        $crate::project!(@field $cell, $field; $($rest)*)
+    };
+    (@step $cell:ident, $field:tt $($rest:tt)*) => {
+        $crate::project!(@field $cell, $field; $($rest)*)
+    };
+    (@segment $field:ident) => {};
+    (@segment $field:literal) => {};
+    (@index $cell:ident, $index:expr; $($rest:tt)*) => {{
+        // The index is evaluated outside of the unsafe block, like any other argument.
+        let index : usize = $index;
+        let ptr = $crate::SuperCell::as_ptr($cell);
+        if false {
+            // Rejects elements which would be unaligned, never runs.
+            let _ = &$crate::__place_of(ptr)[index];
+        }
+        // SAFETY: The pointer comes from a live cell and the place is only computed, not read.
+        let element = unsafe { &raw mut (*ptr)[index] };
+        // SAFETY: The element lives inside the cell and is aligned, as checked above.
+        let cell = unsafe { $crate::__project($cell, element) };
+        $crate::project!(@step cell, $($rest)*)
+    }};
+    (@field $cell:ident, $field:tt; $($rest:tt)*) => {{
+        // Only field names and tuple indices, anything else has no matching rule.
+        $crate::project!(@segment $field);
+        let ptr = $crate::SuperCell::as_ptr($cell);
+        if false {
+            // Fields of packed structs can be unaligned, which makes taking a reference to them a
+            // compile error. Never runs.
+            let _ = &$crate::__place_of(ptr).$field;
+        }
+        // SAFETY: The pointer comes from a live cell and the place is only computed, not read.
+        let field = unsafe { &raw mut (*ptr).$field };
+        // SAFETY: The field lives inside the cell and is aligned, as checked above.
+        let cell = unsafe { $crate::__project($cell, field) };
+        $crate::project!(@step cell, $($rest)*)
+    }};
+    ($cell:expr => $($path:tt)+) => {{
+        let cell : &$crate::SuperCell<_> = $cell;
+        $crate::project!(@step cell, $($path)+)
+    }};
+}
+
+/// Derives a `&SuperCell<U>` for any place reachable from the pointer of a `&SuperCell<T>`
+///
+/// The closure receives the raw pointer of the cell and returns a pointer to the place to
+/// project onto, such as a struct field, a slice element, or an enum payload. This is the
+/// building block underneath [`project!`].
+///
+/// # Safety
+///
+/// The pointer returned by `f` must:
+/// - be derived from the pointer passed to `f`,
+/// - point to a properly aligned and initialized `U` which is in-bounds of the same allocation
+///   as the value in `cell`, with correct metadata if `U` is unsized,
+/// - stay valid for as long as the returned reference lives. In particular the place must not be
+///   moved, freed, or reinterpreted in the meantime, such as by writing a different enum variant
+///   over the payload it points into.
+///
+/// # Examples
+///
+/// ```
+/// use super_cell::{map_cell, SuperCell};
+/// enum Shape {
+///     Circle(f32),
+///     Rect(f32, f32),
+/// }
+///
+/// let shape = SuperCell::new(Shape::Rect(1.0, 2.0));
+/// if let Shape::Rect(..) = shape.get() {
+///     // SAFETY: The variant was checked above and is not changed while `width` is alive.
+///     let width = unsafe {
+///         map_cell(&shape, |ptr| match *ptr {
+///             Shape::Rect(ref mut width, _) => width as *mut f32,
+///             Shape::Circle(_) => unreachable!(),
+///         })
+///     };
+///     *width.get_mut() = 4.0;
+/// }
+/// assert!(matches!(shape.get(), Shape::Rect(width, _) if *width == 4.0));
+/// ```
+#[inline]
+pub unsafe fn map_cell<T : ?Sized, U : ?Sized>(cell : &SuperCell<T>, f : impl FnOnce(*mut T) -> *mut U) -> &SuperCell<U> {
+    // SAFETY: The caller guarantees that the pointer is valid for as long as the cell borrow.
+    unsafe { &*(f(cell.as_ptr()) as *const SuperCell<U>) }
+}
+
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn __project<T : ?Sized, U : ?Sized>(_cell : &SuperCell<T>, field : *mut U) -> &SuperCell<U> {
+    // SAFETY: `field` points inside the cell, so it lives as long as the cell borrow.
+    unsafe { &*(field as *const SuperCell<U>) }
+}
+
+/// Type checks places for [`project!`] in code which never runs
+#[doc(hidden)]
+pub fn __place_of<'a, T : ?Sized>(_ptr : *mut T) -> &'a T {
+    unreachable!()
 }
 
 impl<T> SuperCell<[T]> {
@@ -54,25 +520,98 @@ impl<T, const N: usize> SuperCell<[T; N]> {
         // SAFETY: `Cell<T>` has the same memory layout as `T`.
         unsafe { &*(self as *const SuperCell<[T; N]> as *const [SuperCell<T>; N]) }
     }
+
+    /// Creates an array cell whose elements are computed from their index
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let squares : SuperCell<[usize; 4]> = SuperCell::from_fn(|index| index * index);
+    /// assert_eq!(*squares.get(), [0, 1, 4, 9]);
+    /// ```
+    #[inline]
+    pub fn from_fn(f : impl FnMut(usize) -> T) -> Self {
+        SuperCell::new(core::array::from_fn(f))
+    }
+
+    /// Creates an array cell holding `N` clones of `value`
+    #[inline]
+    pub fn new_with(value : T) -> Self where T : Clone {
+        SuperCell::from_fn(|_| value.clone())
+    }
+}
+
+/// Creates an array of cells whose values are computed from their index
+///
+/// # Examples
+///
+/// ```
+/// use super_cell::{cell_array_from_fn, SuperCell};
+/// let lanes : [SuperCell<Vec<usize>>; 3] = cell_array_from_fn(|index| vec![index]);
+/// lanes[1].get_mut().push(5);
+/// assert_eq!(*lanes[1].get(), [1, 5]);
+/// ```
+#[inline]
+pub fn cell_array_from_fn<T, const N : usize>(mut f : impl FnMut(usize) -> T) -> [SuperCell<T>; N] {
+    core::array::from_fn(|index| SuperCell::new(f(index)))
+}
+
+/// Extension methods for wrapping any value in a `SuperCell`
+///
+/// # Examples
+///
+/// ```
+/// use super_cell::prelude::*;
+/// let cell = vec![1, 2, 3].into_cell();
+/// cell.get_mut().push(4);
+///
+/// let mut value = 10;
+/// *value.as_cell().get_mut() = 11;
+/// assert_eq!(value, 11);
+/// ```
+pub trait SuperCellExt {
+    /// Wraps `self` in a new `SuperCell`, same as `SuperCell::new(self)`
+    fn into_cell(self) -> SuperCell<Self> where Self : Sized;
+
+    /// Views `self` as a `SuperCell`, same as `SuperCell::from_mut(self)`
+    fn as_cell(&mut self) -> &SuperCell<Self>;
+}
+
+impl<T : ?Sized> SuperCellExt for T {
+    #[inline]
+    fn into_cell(self) -> SuperCell<Self> where Self : Sized {
+        SuperCell::new(self)
+    }
+
+    #[inline]
+    fn as_cell(&mut self) -> &SuperCell<Self> {
+        SuperCell::from_mut(self)
+    }
+}
+
+/// Re-exports the cell type and its extension traits
+pub mod prelude {
+    pub use crate::{CollectCells, SuperCell, SuperCellExt};
 }
 
 impl<T: Eq> Eq for SuperCell<T> {}
 impl<T: PartialEq> PartialEq<Self> for SuperCell<T> {
     #[allow(dead_code)]
     fn eq(&self, other: &Self) -> bool {
-        self.get_mut().eq(&other.get_mut())
+        self.get().eq(other.get())
     }
 }
 impl<T: PartialOrd> PartialOrd<Self> for SuperCell<T> {
     #[allow(dead_code)]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.get_mut().partial_cmp(&other.get_mut())
+        self.get().partial_cmp(other.get())
     }
 }
 impl<T : Ord> Ord for SuperCell<T> {
     #[allow(dead_code)]
     fn cmp(&self, other: &Self) -> Ordering {
-        self.get_mut().cmp(&other.get_mut())
+        self.get().cmp(other.get())
     }
 }
 impl <T : Default> Default for SuperCell<T> {
@@ -86,29 +625,34 @@ impl<T: Clone> Clone for SuperCell<T> {
     fn clone(&self) -> Self {
         SuperCell::new(self.get().clone())
     }
+
+    /// Delegates to `T::clone_from`, so existing allocations in `self` can be reused.
+    fn clone_from(&mut self, source : &Self) {
+        self.value.get_mut().clone_from(source.get())
+    }
 }
 impl<T: Hash> Hash for SuperCell<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.get_mut().hash(state)
+        self.get().hash(state)
     }
 }
-impl <T: Debug> Debug for SuperCell<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        self.get_mut().fmt(f)
+impl <T: Debug + ?Sized> Debug for SuperCell<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("SuperCell").field(&self.get()).finish()
     }
 }
 impl <T: Display> Display for SuperCell<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        self.get_mut().fmt(f)
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.get().fmt(f)
     }
 }
-unsafe impl <T> Sync for SuperCell<T> {}
-unsafe impl <T> Send for SuperCell<T> {}
+unsafe impl <T : ?Sized> Sync for SuperCell<T> {}
+unsafe impl <T : ?Sized> Send for SuperCell<T> {}
 
 #[cfg(feature = "serde")]
 impl <T : Serialize> Serialize for SuperCell<T> {
     fn serialize<S : Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        self.get_mut().serialize(serializer)
+        self.get().serialize(serializer)
     }
 }
 
@@ -121,12 +665,13 @@ impl <'de,T : Deserialize<'de>> Deserialize<'de> for SuperCell<T> {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::mpsc;
     use std::thread;
     use std::thread::sleep;
     use std::time::Duration;
     use super::*;
     
-    const TEST_COUNT : usize = 5;
+    const TEST_COUNT : usize = 17;
     static COUNT: SuperCell<usize> = SuperCell::new(0);
 
     struct Test {
@@ -179,17 +724,44 @@ mod tests {
         println!("{}/{TEST_COUNT}: Mutability for Cells as Arrays Successful", *COUNT.get());
     }
 
+    #[test]
+    fn array_constructors() {
+        let cells : [SuperCell<String>; 4] = cell_array_from_fn(|index| index.to_string());
+        cells[0].get_mut().push('!');
+        cells[3].set(String::from("last"));
+        let values : Vec<_> = cells.iter().map(|cell| cell.get().as_str()).collect();
+        assert_eq!(values, ["0!", "1", "2", "last"]);
+
+        let array : SuperCell<[usize; 5]> = SuperCell::from_fn(|index| index * 2);
+        *array.as_array_of_cells()[2].get_mut() += 1;
+        assert_eq!(*array.get(), [0, 2, 5, 6, 8]);
+
+        let repeated = SuperCell::<[Vec<u8>; 3]>::new_with(vec![1]);
+        repeated.as_array_of_cells()[1].get_mut().push(2);
+        assert_eq!(*repeated.get(), [vec![1], vec![1, 2], vec![1]]);
+
+        let empty : [SuperCell<u8>; 0] = cell_array_from_fn(|_| unreachable!());
+        assert!(empty.is_empty());
+        assert_eq!(*SuperCell::<[u8; 0]>::from_fn(|_| unreachable!()).get(), [0u8; 0]);
+        assert_eq!(*SuperCell::<[u8; 0]>::new_with(1).get(), [0u8; 0]);
+        *COUNT.get_mut() += 1;
+        println!("{}/{TEST_COUNT}: Array Constructors Successful", *COUNT.get());
+    }
+
     #[test]
     fn async_mutability() {
         let result = SuperCell::new(10);
+        let (sender, receiver) = mpsc::channel();
         thread::scope(|x| {
-            let reference = result.get_mut();
-            let handle = x.spawn(|| {
+            let reference = &result;
+            let handle = x.spawn(move || {
+                receiver.recv().expect("Failed to receive!");
                 sleep(Duration::from_millis(10));
-                *reference = 11;
+                *reference.get_mut() = 11;
             });
             assert_eq!(*result.get(), 10);
             assert_eq!(*result.get_mut(), 10);
+            sender.send(()).expect("Failed to send!");
             handle.join().expect("Failed to join thread!");
             assert_eq!(*result.get(), 11);
             assert_eq!(*result.get_mut(), 11);
@@ -203,13 +775,290 @@ mod tests {
     #[test]
     fn multiple_mutable_references() {
         let result = SuperCell::new(10);
-        let ref1 = result.get_mut();
-        let ref2 = result.get_mut();
-        let ref3 = result.get();
-        *ref1 = 11;
-        assert_eq!(*ref1, *ref2);
-        assert_eq!(*ref3, *ref2);
+        let ref1 = &result;
+        let ref2 = &result;
+        *ref1.get_mut() = 11;
+        assert_eq!(*ref1.get(), *ref2.get());
+        *ref2.get_mut() += 1;
+        assert_eq!(*ref1.get(), 12);
+        let raw = ref1.as_ptr();
+        unsafe { *raw += 1 };
+        assert_eq!(*ref2.get(), 13);
         *COUNT.get_mut() += 1;
         println!("{}/{TEST_COUNT}: Multiple Mutability for Cell Successful", *COUNT.get());
     }
+
+    #[test]
+    fn extension_trait() {
+        let result = (0..5).collect::<Vec<usize>>().into_cell();
+        result.get_mut().push(5);
+        assert_eq!(*result.get(), vec![0, 1, 2, 3, 4, 5]);
+
+        let mut test = Test {
+            x: 1,
+            list: vec![],
+        };
+        let x = test.x.as_cell();
+        *x.get_mut() = 2;
+        assert_eq!(test.x, 2);
+
+        let mut array = [1, 2, 3, 4];
+        let cell : &SuperCell<[i32]> = array.as_cell();
+        for value in cell.as_slice_of_cells() {
+            *value.get_mut() *= 10;
+        }
+        assert_eq!(array, [10, 20, 30, 40]);
+        *COUNT.get_mut() += 1;
+        println!("{}/{TEST_COUNT}: Extension Trait Successful", *COUNT.get());
+    }
+
+    #[test]
+    fn field_projection() {
+        struct Vec3 {
+            x : f32,
+            y : f32,
+        }
+        struct Player {
+            hp : u32,
+            position : Vec3,
+            pair : (u8, (u8, u8)),
+            slots : [usize; 4],
+        }
+        let result = SuperCell::new(Player {
+            hp: 10,
+            position: Vec3 { x: 0.0, y: 0.0 },
+            pair: (0, (0, 0)),
+            slots: [0; 4],
+        });
+        let hp = project!(&result => hp);
+        let y = project!(&result => position.y);
+        let inner = project!(&result => pair.1.0);
+        let slot = project!(&result => slots[2]);
+        let offset = 1;
+        let last = project!(&result => slots[offset + 2]);
+        *hp.get_mut() -= 5;
+        *y.get_mut() = 2.5;
+        *inner.get_mut() = 7;
+        *slot.get_mut() = 3;
+        *last.get_mut() = 4;
+        assert_eq!(result.get().hp, 5);
+        assert_eq!(result.get().position.x, 0.0);
+        assert_eq!(result.get().position.y, 2.5);
+        assert_eq!(result.get().pair, (0, (7, 0)));
+        assert_eq!(result.get().slots, [0, 0, 3, 4]);
+        *COUNT.get_mut() += 1;
+        println!("{}/{TEST_COUNT}: Field Projection Successful", *COUNT.get());
+    }
+
+    #[test]
+    fn raw_projection() {
+        #[derive(Debug, PartialEq)]
+        enum State {
+            Idle,
+            Running(usize, Vec<usize>),
+        }
+        let result = SuperCell::new([State::Idle, State::Running(0, vec![])]);
+        let slice : &SuperCell<[State]> = &result;
+        let second = unsafe { map_cell(slice, |ptr| ptr.cast::<State>().add(1)) };
+        if let State::Running(..) = second.get() {
+            let count = unsafe {
+                map_cell(second, |ptr| match *ptr {
+                    State::Running(ref mut count, _) => count as *mut usize,
+                    State::Idle => unreachable!(),
+                })
+            };
+            let list = unsafe {
+                map_cell(second, |ptr| match *ptr {
+                    State::Running(_, ref mut list) => list as *mut Vec<usize>,
+                    State::Idle => unreachable!(),
+                })
+            };
+            *count.get_mut() = 2;
+            list.get_mut().push(1);
+        }
+        assert_eq!(result.get()[0], State::Idle);
+        assert_eq!(result.get()[1], State::Running(2, vec![1]));
+        *COUNT.get_mut() += 1;
+        println!("{}/{TEST_COUNT}: Raw Projection Successful", *COUNT.get());
+    }
+
+    #[test]
+    fn scoped_access() {
+        let names = SuperCell::new(vec![String::from("a"), String::from("b")]);
+        let lengths = SuperCell::new(vec![]);
+        names.with_mut(|list| list.push(String::from("cc")));
+        assert_eq!(names.with(|list| list.len()), 3);
+        let last = names.with(|list| list.last().cloned());
+        assert_eq!(last.as_deref(), Some("cc"));
+        names.with(|list| {
+            lengths.with_mut(|lengths| lengths.extend(list.iter().map(String::len)));
+        });
+        assert_eq!(*lengths.get(), vec![1, 1, 2]);
+
+        let mut array = [1, 2, 3];
+        let slice : &SuperCell<[i32]> = SuperCell::from_mut(&mut array);
+        slice.with_mut(|slice| slice.reverse());
+        assert_eq!(slice.with(|slice| slice.to_vec()), vec![3, 2, 1]);
+        *COUNT.get_mut() += 1;
+        println!("{}/{TEST_COUNT}: Scoped Access Successful", *COUNT.get());
+    }
+
+    #[test]
+    fn fallible_updates() {
+        let result = SuperCell::new(vec![1, 2, 3]);
+        let pushed : Result<usize, &str> = result.try_update(|list| {
+            list.push(4);
+            Ok(list.len())
+        });
+        assert_eq!(pushed, Ok(4));
+        let failed : Result<usize, &str> = result.try_update(|list| list.first().copied().filter(|first| *first > 1).ok_or("too small"));
+        assert_eq!(failed, Err("too small"));
+
+        let failed = result.try_replace_with(|list| if list.len() > 10 { Ok(vec![]) } else { Err("too short") });
+        assert_eq!(failed, Err("too short"));
+        assert_eq!(*result.get(), vec![1, 2, 3, 4]);
+        let old : Result<_, &str> = result.try_replace_with(|list| Ok(list.iter().map(|value| value * 2).collect()));
+        assert_eq!(old, Ok(vec![1, 2, 3, 4]));
+        assert_eq!(*result.get(), vec![2, 4, 6, 8]);
+        *COUNT.get_mut() += 1;
+        println!("{}/{TEST_COUNT}: Fallible Updates Successful", *COUNT.get());
+    }
+
+    #[test]
+    fn clone_from_reuses_allocation() {
+        let source = SuperCell::new("a".repeat(1000));
+        let mut result = SuperCell::new(String::with_capacity(4096));
+        let pointer = result.get().as_ptr();
+        result.clone_from(&source);
+        assert_eq!(result.get().as_ptr(), pointer);
+        assert_eq!(result, source.clone());
+
+        let source = SuperCell::new(vec![vec![1u8; 16], vec![2u8; 16]]);
+        let mut result = SuperCell::new(vec![Vec::with_capacity(32), Vec::with_capacity(32)]);
+        let pointers : Vec<_> = result.get().iter().map(|list| list.as_ptr()).collect();
+        result.clone_from(&source);
+        assert_eq!(result.get().iter().map(|list| list.as_ptr()).collect::<Vec<_>>(), pointers);
+        assert_eq!(result, source);
+        *COUNT.get_mut() += 1;
+        println!("{}/{TEST_COUNT}: Clone From Successful", *COUNT.get());
+    }
+
+    #[test]
+    fn set_replace_take() {
+        let result = SuperCell::new(vec![1]);
+        result.set(vec![2]);
+        assert_eq!(result.replace(vec![3]), vec![2]);
+        assert_eq!(result.take(), vec![3]);
+        assert!(result.get().is_empty());
+        result.set(vec![4]);
+        assert_eq!(result.into_inner(), vec![4]);
+        *COUNT.get_mut() += 1;
+        println!("{}/{TEST_COUNT}: Set, Replace and Take Successful", *COUNT.get());
+    }
+
+    #[test]
+    fn volatile_access() {
+        #[repr(C)]
+        struct Registers {
+            control : SuperCell<u32>,
+            status : SuperCell<u32>,
+        }
+        let registers = Registers {
+            control: SuperCell::new(0),
+            status: SuperCell::new(0xFF),
+        };
+        registers.control.write_volatile(0b101);
+        assert_eq!(registers.control.read_volatile(), 0b101);
+        assert_eq!(registers.status.read_volatile(), 0xFF);
+        assert_eq!(*registers.control.get(), 0b101);
+        *COUNT.get_mut() += 1;
+        println!("{}/{TEST_COUNT}: Volatile Access Successful", *COUNT.get());
+    }
+
+    #[test]
+    #[allow(clippy::mutable_key_type)]
+    fn comparisons_and_keys() {
+        use std::collections::{BTreeMap, HashMap};
+        let mut hashed = HashMap::new();
+        hashed.insert(SuperCell::new(String::from("a")), 1);
+        hashed.insert(SuperCell::new(String::from("b")), 2);
+        assert_eq!(hashed.get(&SuperCell::new(String::from("b"))), Some(&2));
+        let mut ordered = BTreeMap::new();
+        for key in [3, 1, 2] {
+            ordered.insert(SuperCell::new(key), key * 10);
+        }
+        assert_eq!(ordered.keys().map(|key| *key.get()).collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(ordered.get(&SuperCell::new(2)), Some(&20));
+
+        let nan = SuperCell::new(f64::NAN);
+        let one = SuperCell::new(1.0);
+        assert_ne!(nan, nan);
+        assert_eq!(nan.partial_cmp(&one), None);
+        assert_eq!(one.partial_cmp(&nan), None);
+        assert!(nan.lt(&one) == one.lt(&nan) && nan.ne(&one));
+        assert_eq!(one.partial_cmp(&SuperCell::new(2.0)), Some(Ordering::Less));
+        *COUNT.get_mut() += 1;
+        println!("{}/{TEST_COUNT}: Comparisons and Keys Successful", *COUNT.get());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+        struct Inner {
+            id : u32,
+            tags : Vec<String>,
+        }
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        struct Plain {
+            name : String,
+            children : Vec<Inner>,
+        }
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        struct Nested {
+            name : SuperCell<String>,
+            children : SuperCell<Vec<SuperCell<Inner>>>,
+        }
+        static LIMIT : SuperCell<(u8, bool)> = SuperCell::new((3, true));
+
+        let inner = Inner { id: 1, tags: vec![String::from("a")] };
+        let plain = Plain { name: String::from("root"), children: vec![inner.clone(), inner.clone()] };
+        let nested = Nested {
+            name: SuperCell::new(String::from("root")),
+            children: SuperCell::new(vec![SuperCell::new(inner.clone()), SuperCell::new(inner)]),
+        };
+        let json = serde_json::to_string(&nested).unwrap();
+        assert_eq!(json, serde_json::to_string(&plain).unwrap());
+        assert_eq!(serde_json::from_str::<Nested>(&json).unwrap(), nested);
+
+        let json = serde_json::to_string(&LIMIT).unwrap();
+        assert_eq!(json, "[3,true]");
+        assert_eq!(serde_json::from_str::<SuperCell<(u8, bool)>>(&json).unwrap(), LIMIT);
+        *COUNT.get_mut() += 1;
+        println!("{}/{TEST_COUNT}: Serde Round Trip Successful", *COUNT.get());
+    }
+
+    #[test]
+    fn debug_formatting() {
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            level : SuperCell<u8>,
+            name : SuperCell<&'static str>,
+        }
+        assert_eq!(format!("{:?}", SuperCell::new(1)), "SuperCell(1)");
+        let config = Config { level: SuperCell::new(2), name: SuperCell::new("main") };
+        assert_eq!(format!("{config:?}"), r#"Config { level: SuperCell(2), name: SuperCell("main") }"#);
+        assert_eq!(format!("{config:#?}"), "Config {\n    level: SuperCell(\n        2,\n    ),\n    name: SuperCell(\n        \"main\",\n    ),\n}");
+
+        let mut array = [1, 2];
+        let slice : &SuperCell<[i32]> = SuperCell::from_mut(&mut array);
+        assert_eq!(format!("{slice:?}"), "SuperCell([1, 2])");
+        let mut text = String::from("text");
+        assert_eq!(format!("{:?}", SuperCell::from_mut(text.as_mut_str())), r#"SuperCell("text")"#);
+        *COUNT.get_mut() += 1;
+        println!("{}/{TEST_COUNT}: Debug Formatting Successful", *COUNT.get());
+    }
 }
@@ -0,0 +1,87 @@
+/// Declares a thread local `SuperCell` along with a module of accessor functions
+///
+/// Each declaration `static NAME : Type = init;` expands to a module `NAME` holding a
+/// `thread_local!` cell and the functions `with`, `set` and `get_cloned`, the last one only
+/// callable when `Type : Clone`. The initializer runs lazily, the first time a thread touches
+/// the cell. Declarations go at module level, the generated module looks up `Type` and `init`
+/// through `use super::*`.
+///
+/// # Examples
+///
+/// ```
+/// use super_cell::local_cell;
+/// local_cell! {
+///     static SCRATCH : Vec<u32> = Vec::with_capacity(16);
+/// }
+///
+/// SCRATCH::with(|scratch| scratch.get_mut().push(1));
+/// SCRATCH::set(vec![2]);
+/// assert_eq!(SCRATCH::get_cloned(), vec![2]);
+/// ```
+#[macro_export]
+macro_rules! local_cell {
+    ($($(#[$attr:meta])* $vis:vis static $name:ident : $ty:ty = $init:expr;)+) => {$(
+        $(#[$attr])*
+        #[allow(non_snake_case)]
+        $vis mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            ::std::thread_local! {
+                static CELL : $crate::SuperCell<$ty> = $crate::SuperCell::new($init);
+            }
+
+            /// Calls `f` with this thread's cell
+            #[allow(dead_code)]
+            pub fn with<R>(f : impl FnOnce(&$crate::SuperCell<$ty>) -> R) -> R {
+                CELL.with(f)
+            }
+
+            /// Replaces this thread's value, dropping the previous one
+            #[allow(dead_code)]
+            pub fn set(value : $ty) {
+                CELL.with(|cell| cell.set(value))
+            }
+
+            /// Returns a clone of this thread's value
+            #[allow(dead_code)]
+            pub fn get_cloned() -> $ty where for<'a> $ty : Clone {
+                CELL.with(|cell| cell.get().clone())
+            }
+        }
+    )+};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    struct Token(u32);
+
+    local_cell! {
+        static COUNTER : u32 = 0;
+        static NAMES : Vec<String> = vec![String::from("main")];
+        static TOKEN : Token = Token(1);
+    }
+
+    #[test]
+    fn isolated_per_thread() {
+        let spawn = |value : u32, name : &'static str| thread::spawn(move || {
+            COUNTER::set(value);
+            NAMES::with(|names| names.get_mut().push(name.to_string()));
+            thread::yield_now();
+            (COUNTER::get_cloned(), NAMES::get_cloned())
+        });
+        let first = spawn(1, "first");
+        let second = spawn(2, "second");
+        assert_eq!(first.join().expect("Failed to join thread!"), (1, vec![String::from("main"), String::from("first")]));
+        assert_eq!(second.join().expect("Failed to join thread!"), (2, vec![String::from("main"), String::from("second")]));
+        assert_eq!(COUNTER::get_cloned(), 0);
+    }
+
+    #[test]
+    fn non_clone_values() {
+        TOKEN::with(|token| token.get_mut().0 += 1);
+        assert_eq!(TOKEN::with(|token| token.get().0), 2);
+    }
+}
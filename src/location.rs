@@ -0,0 +1,101 @@
+use core::panic::Location;
+use crate::SuperCell;
+
+impl<T : ?Sized> SuperCell<T> {
+    /// Returns the call site that last took mutable access to the value
+    ///
+    /// Only tracked with the `debug-trace` feature in builds with `debug_assertions`, otherwise
+    /// this is always `None`. Writes are recorded by the `#[track_caller]` entry points on the
+    /// cell itself, such as [`SuperCell::set`], [`SuperCell::get_mut`] and
+    /// [`SuperCell::borrow_mut`], helpers built on top of them report their own location.
+    ///
+    /// Locations are kept in a side table keyed by address, so a new cell placed where an old
+    /// one lived reports the old writer until it is first written. Zero sized values are not
+    /// tracked.
+    #[inline]
+    pub fn last_write_location(&self) -> Option<&'static Location<'static>> {
+        #[cfg(all(feature = "debug-trace", debug_assertions))]
+        return table::last_write(self);
+        #[cfg(not(all(feature = "debug-trace", debug_assertions)))]
+        None
+    }
+}
+
+/// Records the caller as the last writer of `cell`
+#[inline(always)]
+#[track_caller]
+pub(crate) fn record_write<T : ?Sized>(cell : &SuperCell<T>) {
+    #[cfg(all(feature = "debug-trace", debug_assertions))]
+    table::record(cell, Location::caller());
+    #[cfg(not(all(feature = "debug-trace", debug_assertions)))]
+    let _ = cell;
+}
+
+#[cfg(all(feature = "debug-trace", debug_assertions))]
+mod table {
+    use core::panic::Location;
+    use std::collections::BTreeMap;
+    use std::sync::{Mutex, PoisonError};
+    use crate::SuperCell;
+
+    static WRITERS : Mutex<BTreeMap<usize, &'static Location<'static>>> = Mutex::new(BTreeMap::new());
+
+    pub(super) fn record<T : ?Sized>(cell : &SuperCell<T>, location : &'static Location<'static>) {
+        if let Some(key) = cell.tracking_key() {
+            WRITERS.lock().unwrap_or_else(PoisonError::into_inner).insert(key, location);
+        }
+    }
+
+    pub(super) fn last_write<T : ?Sized>(cell : &SuperCell<T>) -> Option<&'static Location<'static>> {
+        let key = cell.tracking_key()?;
+        WRITERS.lock().unwrap_or_else(PoisonError::into_inner).get(&key).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn first_writer(cell : &SuperCell<u32>) {
+        cell.set(1);
+    }
+
+    fn second_writer(cell : &SuperCell<u32>) -> u32 {
+        let line = line!() + 1;
+        *cell.borrow_mut() += 1;
+        line
+    }
+
+    #[cfg(all(feature = "debug-trace", debug_assertions))]
+    #[test]
+    fn reports_second_writer() {
+        let cell = SuperCell::new(0);
+        first_writer(&cell);
+        let line = second_writer(&cell);
+        let location = cell.last_write_location().expect("write should be recorded");
+        assert_eq!(location.file(), file!());
+        assert_eq!(location.line(), line);
+        assert_eq!(*cell.get(), 2);
+    }
+
+    #[cfg(all(feature = "debug-trace", debug_assertions))]
+    #[test]
+    fn forwarded_through_entry_points() {
+        let cell = SuperCell::new(String::new());
+        let line = line!() + 1;
+        cell.take();
+        assert_eq!(cell.last_write_location().map(Location::line), Some(line));
+        let line = line!() + 1;
+        cell.with_mut(|text| text.push('a'));
+        assert_eq!(cell.last_write_location().map(Location::line), Some(line));
+    }
+
+    #[cfg(not(all(feature = "debug-trace", debug_assertions)))]
+    #[test]
+    fn untracked_without_feature() {
+        let cell = SuperCell::new(0);
+        first_writer(&cell);
+        second_writer(&cell);
+        assert!(cell.last_write_location().is_none());
+    }
+}
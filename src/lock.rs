@@ -0,0 +1,197 @@
+use core::hint::spin_loop;
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use lock_api::{GuardSend, RawMutex, RawRwLock};
+
+/// Spinning mutual exclusion lock, for use with `lock_api::Mutex`
+pub struct RawSuperLock {
+    locked : AtomicBool
+}
+
+/// Spinning reader-writer lock, for use with `lock_api::RwLock`
+///
+/// Readers are not blocked by waiting writers, so a steady stream of readers can starve them.
+pub struct RawSuperRwLock {
+    /// Number of readers, or `WRITER` while locked exclusively
+    state : AtomicUsize
+}
+
+/// Mutex spinning on a [`RawSuperLock`]
+///
+/// # Examples
+///
+/// ```
+/// use super_cell::SuperMutex;
+/// static FRAMES : SuperMutex<Vec<u32>> = SuperMutex::new(Vec::new());
+/// FRAMES.lock().push(1);
+/// assert_eq!(*FRAMES.lock(), [1]);
+/// ```
+pub type SuperMutex<T> = lock_api::Mutex<RawSuperLock, T>;
+
+/// Reader-writer lock spinning on a [`RawSuperRwLock`]
+pub type SuperRwLock<T> = lock_api::RwLock<RawSuperRwLock, T>;
+
+const WRITER : usize = usize::MAX;
+
+// SAFETY: Acquiring needs the swap from unlocked to locked, which only one thread can win.
+unsafe impl RawMutex for RawSuperLock {
+    const INIT : Self = Self { locked : AtomicBool::new(false) };
+
+    type GuardMarker = GuardSend;
+
+    fn lock(&self) {
+        while !self.try_lock() {
+            while self.is_locked() {
+                spin_loop();
+            }
+        }
+    }
+
+    #[inline]
+    fn try_lock(&self) -> bool {
+        self.locked.compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed).is_ok()
+    }
+
+    #[inline]
+    unsafe fn unlock(&self) {
+        self.locked.store(false, Ordering::Release);
+    }
+
+    #[inline]
+    fn is_locked(&self) -> bool {
+        self.locked.load(Ordering::Relaxed)
+    }
+}
+
+// SAFETY: Writers need the state to go from no readers to `WRITER`, and readers only increment
+// it while it is not `WRITER`.
+unsafe impl RawRwLock for RawSuperRwLock {
+    const INIT : Self = Self { state : AtomicUsize::new(0) };
+
+    type GuardMarker = GuardSend;
+
+    fn lock_shared(&self) {
+        while !self.try_lock_shared() {
+            spin_loop();
+        }
+    }
+
+    fn try_lock_shared(&self) -> bool {
+        // Retries when other readers change the count or the exchange fails spuriously, so this
+        // only fails while a writer holds the lock. `WRITER - 1` readers would make the next one
+        // look like a writer.
+        self.state.fetch_update(Ordering::Acquire, Ordering::Relaxed, |state| (state < WRITER - 1).then(|| state + 1)).is_ok()
+    }
+
+    #[inline]
+    unsafe fn unlock_shared(&self) {
+        self.state.fetch_sub(1, Ordering::Release);
+    }
+
+    fn lock_exclusive(&self) {
+        while !self.try_lock_exclusive() {
+            while self.is_locked() {
+                spin_loop();
+            }
+        }
+    }
+
+    #[inline]
+    fn try_lock_exclusive(&self) -> bool {
+        self.state.compare_exchange(0, WRITER, Ordering::Acquire, Ordering::Relaxed).is_ok()
+    }
+
+    #[inline]
+    unsafe fn unlock_exclusive(&self) {
+        self.state.store(0, Ordering::Release);
+    }
+
+    #[inline]
+    fn is_locked(&self) -> bool {
+        self.state.load(Ordering::Relaxed) != 0
+    }
+
+    #[inline]
+    fn is_locked_exclusive(&self) -> bool {
+        self.state.load(Ordering::Relaxed) == WRITER
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use lock_api::{MutexGuard, RwLockWriteGuard};
+    use super::*;
+
+    struct Player {
+        hp : u32,
+        name : &'static str
+    }
+
+    #[test]
+    fn mutex() {
+        let mutex = SuperMutex::new(Player { hp : 10, name : "a" });
+        assert!(!mutex.is_locked());
+        let guard = mutex.lock();
+        assert!(mutex.is_locked());
+        assert!(mutex.try_lock().is_none());
+        let mut hp = MutexGuard::map(guard, |player| &mut player.hp);
+        *hp -= 1;
+        drop(hp);
+        assert!(!mutex.is_locked());
+        let player = mutex.try_lock().expect("The mutex should be unlocked");
+        assert_eq!((player.hp, player.name), (9, "a"));
+    }
+
+    #[test]
+    fn rw_lock() {
+        let lock = SuperRwLock::new(Player { hp : 10, name : "a" });
+        let (first, second) = (lock.read(), lock.read());
+        assert!(lock.try_write().is_none());
+        assert!(lock.is_locked() && !lock.is_locked_exclusive());
+        assert_eq!(first.hp + second.hp, 20);
+        drop((first, second));
+        let mut name = RwLockWriteGuard::map(lock.write(), |player| &mut player.name);
+        assert!(lock.is_locked_exclusive());
+        assert!(lock.try_read().is_none());
+        *name = "b";
+        drop(name);
+        assert_eq!(lock.read().name, "b");
+    }
+
+    #[test]
+    fn concurrent_try_read() {
+        let lock = SuperRwLock::new(1u32);
+        let rounds = if cfg!(miri) { 50 } else { 10_000 };
+        thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..rounds {
+                        let guard = lock.try_read().expect("no writer holds the lock");
+                        assert_eq!(*guard, 1);
+                        thread::yield_now();
+                    }
+                });
+            }
+        });
+        assert!(!lock.is_locked());
+    }
+
+    #[test]
+    fn contention() {
+        let mutex = SuperMutex::new(0u64);
+        let lock = SuperRwLock::new(0u64);
+        thread::scope(|scope| {
+            for _ in 0..2 {
+                scope.spawn(|| {
+                    for _ in 0..10_000 {
+                        *mutex.lock() += 1;
+                        *lock.write() += 1;
+                        let _ = *lock.read();
+                    }
+                });
+            }
+        });
+        assert_eq!(mutex.into_inner(), 20_000);
+        assert_eq!(lock.into_inner(), 20_000);
+    }
+}
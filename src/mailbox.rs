@@ -0,0 +1,248 @@
+//! Single slot handoff of values between threads
+
+use alloc::sync::Arc;
+use core::cell::Cell;
+use core::hint::spin_loop;
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicU8, Ordering};
+use crate::SuperCell;
+
+const EMPTY : u8 = 0;
+const FULL : u8 = 1;
+/// Set while a thread accesses the slot, the thread that set it owns the value until it stores
+/// `EMPTY` or `FULL` again
+const BUSY : u8 = 2;
+
+/// Slot holding at most one value, handed from a producer to a consumer
+///
+/// All methods take `&self` and are safe to call from any number of threads, the slot is only
+/// touched by the thread which moved the state to `BUSY`. [`MailboxCell::split`] gives the
+/// single producer, single consumer pair of handles.
+///
+/// # Examples
+///
+/// ```
+/// use std::thread;
+/// use super_cell::MailboxCell;
+/// let (sender, receiver) = MailboxCell::new().split();
+/// thread::spawn(move || assert!(sender.send(1).is_ok()));
+/// let value = loop {
+///     if let Some(value) = receiver.try_recv() {
+///         break value;
+///     }
+/// };
+/// assert_eq!(value, 1);
+/// ```
+pub struct MailboxCell<T> {
+    state : AtomicU8,
+    slot : SuperCell<Option<T>>,
+    _no_auto_traits : PhantomData<*mut T>
+}
+
+unsafe impl<T : Send> Send for MailboxCell<T> {}
+unsafe impl<T : Send> Sync for MailboxCell<T> {}
+
+/// Sending half of a [`MailboxCell`]
+///
+/// `Send` but not `Sync`, so there is a single producer at a time:
+///
+/// ```compile_fail
+/// use std::thread;
+/// use super_cell::MailboxCell;
+/// let (sender, _receiver) = MailboxCell::<u32>::new().split();
+/// thread::scope(|scope| {
+///     scope.spawn(|| sender.send(1));
+///     scope.spawn(|| sender.send(2));
+/// });
+/// ```
+pub struct Sender<T> {
+    mailbox : Arc<MailboxCell<T>>,
+    _not_sync : PhantomData<Cell<()>>
+}
+
+/// Receiving half of a [`MailboxCell`]
+///
+/// `Send` but not `Sync`, so there is a single consumer at a time:
+///
+/// ```compile_fail
+/// use std::thread;
+/// use super_cell::MailboxCell;
+/// let (_sender, receiver) = MailboxCell::<u32>::new().split();
+/// thread::scope(|scope| {
+///     scope.spawn(|| receiver.try_recv());
+///     scope.spawn(|| receiver.try_recv());
+/// });
+/// ```
+pub struct Receiver<T> {
+    mailbox : Arc<MailboxCell<T>>,
+    _not_sync : PhantomData<Cell<()>>
+}
+
+impl<T> MailboxCell<T> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            state : AtomicU8::new(EMPTY),
+            slot : SuperCell::new(None),
+            _no_auto_traits : PhantomData
+        }
+    }
+
+    /// Moves the state from `from` to `BUSY`, spinning while another thread holds it
+    fn acquire(&self, from : u8) -> Result<(), u8> {
+        loop {
+            match self.state.compare_exchange_weak(from, BUSY, Ordering::Acquire, Ordering::Relaxed) {
+                Ok(_) => return Ok(()),
+                Err(BUSY) => spin_loop(),
+                Err(state) if state != from => return Err(state),
+                Err(_) => {}
+            }
+        }
+    }
+
+    /// Puts `value` in the slot, or gives it back if the slot is full
+    pub fn send(&self, value : T) -> Result<(), T> {
+        if self.acquire(EMPTY).is_err() {
+            return Err(value);
+        }
+        self.slot.set(Some(value));
+        self.state.store(FULL, Ordering::Release);
+        Ok(())
+    }
+
+    /// Puts `value` in the slot, returning the value it displaced if the slot was full
+    pub fn send_overwrite(&self, value : T) -> Option<T> {
+        let mut state = EMPTY;
+        while let Err(current) = self.acquire(state) {
+            state = current;
+        }
+        let previous = self.slot.replace(Some(value));
+        self.state.store(FULL, Ordering::Release);
+        previous
+    }
+
+    /// Takes the value out of the slot, if there is one
+    pub fn try_recv(&self) -> Option<T> {
+        self.acquire(FULL).ok()?;
+        let value = self.slot.take();
+        self.state.store(EMPTY, Ordering::Release);
+        value
+    }
+
+    /// Returns `true` if the slot holds no value
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.state.load(Ordering::Acquire) == EMPTY
+    }
+
+    /// Consumes the mailbox, returning the value in the slot
+    #[inline]
+    pub fn into_inner(self) -> Option<T> {
+        self.slot.into_inner()
+    }
+
+    /// Splits the mailbox into the handles of its single producer and single consumer
+    pub fn split(self) -> (Sender<T>, Receiver<T>) {
+        let mailbox = Arc::new(self);
+        (Sender { mailbox : Arc::clone(&mailbox), _not_sync : PhantomData }, Receiver { mailbox, _not_sync : PhantomData })
+    }
+}
+
+impl<T> Default for MailboxCell<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Sender<T> {
+    /// Same as [`MailboxCell::send`]
+    #[inline]
+    pub fn send(&self, value : T) -> Result<(), T> {
+        self.mailbox.send(value)
+    }
+
+    /// Same as [`MailboxCell::send_overwrite`]
+    #[inline]
+    pub fn send_overwrite(&self, value : T) -> Option<T> {
+        self.mailbox.send_overwrite(value)
+    }
+}
+
+impl<T> Receiver<T> {
+    /// Same as [`MailboxCell::try_recv`]
+    #[inline]
+    pub fn try_recv(&self) -> Option<T> {
+        self.mailbox.try_recv()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use super::*;
+
+    const VALUES : usize = if cfg!(miri) { 500 } else { 300_000 };
+
+    #[test]
+    fn single_thread() {
+        let mailbox = MailboxCell::new();
+        assert_eq!(mailbox.try_recv(), None);
+        assert_eq!(mailbox.send(1), Ok(()));
+        assert_eq!(mailbox.send(2), Err(2));
+        assert!(!mailbox.is_empty());
+        assert_eq!(mailbox.send_overwrite(3), Some(1));
+        assert_eq!(mailbox.try_recv(), Some(3));
+        assert_eq!(mailbox.send_overwrite(4), None);
+        assert_eq!(mailbox.into_inner(), Some(4));
+    }
+
+    #[test]
+    fn no_value_lost() {
+        let (sender, receiver) = MailboxCell::new().split();
+        let producer = thread::spawn(move || {
+            for mut value in 0..VALUES {
+                while let Err(rejected) = sender.send(value) {
+                    value = rejected;
+                    thread::yield_now();
+                }
+            }
+        });
+        let mut received = 0;
+        while received < VALUES {
+            match receiver.try_recv() {
+                Some(value) => {
+                    assert_eq!(value, received);
+                    received += 1;
+                }
+                None => thread::yield_now()
+            }
+        }
+        producer.join().expect("Failed to join thread!");
+        assert_eq!(receiver.try_recv(), None);
+    }
+
+    #[test]
+    fn newest_wins() {
+        let (sender, receiver) = MailboxCell::new().split();
+        let producer = thread::spawn(move || {
+            let mut displaced = 0;
+            for value in 0..VALUES {
+                displaced += usize::from(sender.send_overwrite(value).is_some());
+                if value % 64 == 0 {
+                    thread::yield_now();
+                }
+            }
+            displaced
+        });
+        let mut received = Vec::new();
+        while received.last() != Some(&(VALUES - 1)) {
+            match receiver.try_recv() {
+                Some(value) => received.push(value),
+                None => thread::yield_now()
+            }
+        }
+        let displaced = producer.join().expect("Failed to join thread!");
+        assert!(received.is_sorted_by(|a, b| a < b));
+        assert_eq!(received.len() + displaced, VALUES);
+    }
+}
@@ -0,0 +1,268 @@
+use core::mem::{ManuallyDrop, MaybeUninit};
+use crate::SuperCell;
+
+impl<T> SuperCell<ManuallyDrop<T>> {
+    /// Creates a cell whose value is never dropped by the cell itself
+    #[inline]
+    pub const fn new_manual(value : T) -> Self {
+        SuperCell::new(ManuallyDrop::new(value))
+    }
+
+    /// Moves the value out, leaving the slot logically empty
+    ///
+    /// # Safety
+    ///
+    /// The slot must not be empty, that is the value must not have been taken or dropped in
+    /// place since the cell was created or last set. Afterwards the slot is empty, so the value
+    /// must not be read, taken or dropped again until a new one is set, and no reference to it
+    /// may be alive.
+    #[inline]
+    pub unsafe fn take_manual(&self) -> T {
+        // SAFETY: Guaranteed by the caller.
+        unsafe { ManuallyDrop::take(self.get_mut()) }
+    }
+
+    /// Drops the value in place, leaving the slot logically empty
+    ///
+    /// # Safety
+    ///
+    /// Same as [`take_manual`](Self::take_manual).
+    #[inline]
+    pub unsafe fn drop_in_place(&self) {
+        // SAFETY: Guaranteed by the caller.
+        unsafe { ManuallyDrop::drop(self.get_mut()) }
+    }
+
+    /// Gives up on the value without dropping it
+    ///
+    /// This does nothing at runtime, since the cell never drops its value, and only makes the
+    /// leak explicit. The slot can be reused with `set(ManuallyDrop::new(value))`, which does not
+    /// drop the forgotten value either.
+    #[inline]
+    pub fn forget_value(&self) {}
+}
+
+/// Cell whose value can be taken, dropped or forgotten explicitly, panicking on misuse
+///
+/// Tracks whether the slot holds a value, so accessing a taken value or dropping it twice panics
+/// instead of being undefined behavior like with [`SuperCell::take_manual`]. A value still in the
+/// slot is dropped with the cell.
+///
+/// # Examples
+///
+/// ```
+/// use super_cell::ManualCell;
+/// let cell = ManualCell::new("a");
+/// assert_eq!(cell.take(), "a");
+/// assert!(!cell.is_occupied());
+/// cell.set("b");
+/// cell.forget();
+/// ```
+pub struct ManualCell<T> {
+    occupied : SuperCell<bool>,
+    value : SuperCell<MaybeUninit<T>>
+}
+
+impl<T> ManualCell<T> {
+    #[inline]
+    pub const fn new(value : T) -> Self {
+        Self {
+            occupied : SuperCell::new(true),
+            value : SuperCell::new(MaybeUninit::new(value))
+        }
+    }
+
+    /// Creates a cell with an empty slot
+    #[inline]
+    pub const fn empty() -> Self {
+        Self {
+            occupied : SuperCell::new(false),
+            value : SuperCell::new(MaybeUninit::uninit())
+        }
+    }
+
+    /// Returns `true` if the slot holds a value
+    #[inline]
+    pub fn is_occupied(&self) -> bool {
+        *self.occupied.get()
+    }
+
+    #[track_caller]
+    fn vacate(&self) {
+        assert!(self.is_occupied(), "the slot is empty");
+        self.occupied.set(false);
+    }
+
+    /// Returns a shared reference to the value
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slot is empty.
+    #[inline]
+    #[track_caller]
+    pub fn get(&self) -> &T {
+        assert!(self.is_occupied(), "the slot is empty");
+        // SAFETY: The slot is occupied.
+        unsafe { self.value.get().assume_init_ref() }
+    }
+
+    /// Returns a mutable reference to the value
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slot is empty.
+    #[inline]
+    #[track_caller]
+    #[allow(clippy::mut_from_ref)]
+    pub fn get_mut(&self) -> &mut T {
+        assert!(self.is_occupied(), "the slot is empty");
+        // SAFETY: The slot is occupied.
+        unsafe { self.value.get_mut().assume_init_mut() }
+    }
+
+    /// Puts `value` in the slot, dropping the previous value if there is one
+    #[inline]
+    pub fn set(&self, value : T) {
+        if self.is_occupied() {
+            self.drop_value();
+        }
+        self.value.set(MaybeUninit::new(value));
+        self.occupied.set(true);
+    }
+
+    /// Moves the value out, leaving the slot empty
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slot is empty.
+    #[inline]
+    #[track_caller]
+    pub fn take(&self) -> T {
+        self.vacate();
+        // SAFETY: The slot was occupied and is now marked empty, so the value is read once.
+        unsafe { self.value.get().assume_init_read() }
+    }
+
+    /// Moves the value out if there is one
+    #[inline]
+    pub fn try_take(&self) -> Option<T> {
+        self.is_occupied().then(|| self.take())
+    }
+
+    /// Drops the value in place, leaving the slot empty
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slot is empty.
+    #[inline]
+    #[track_caller]
+    pub fn drop_value(&self) {
+        self.vacate();
+        // SAFETY: The slot was occupied and is now marked empty, so the value is dropped once.
+        unsafe { self.value.get_mut().assume_init_drop() }
+    }
+
+    /// Leaks the value, leaving the slot empty
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slot is empty.
+    #[inline]
+    #[track_caller]
+    pub fn forget(&self) {
+        self.vacate();
+    }
+}
+
+impl<T> Default for ManualCell<T> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T> Drop for ManualCell<T> {
+    fn drop(&mut self) {
+        if self.is_occupied() {
+            self.drop_value();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use super::*;
+
+    /// Counts drops into the cell it points to
+    struct Counted<'a>(&'a SuperCell<usize>);
+
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            *self.0.get_mut() += 1;
+        }
+    }
+
+    #[test]
+    fn raw_helpers() {
+        let drops = SuperCell::new(0);
+        let cell = SuperCell::new_manual(Counted(&drops));
+        // SAFETY: The slot is occupied, and set again before each later use.
+        unsafe {
+            let value = cell.take_manual();
+            assert_eq!(*drops.get(), 0);
+            drop(value);
+            assert_eq!(*drops.get(), 1);
+            cell.set(ManuallyDrop::new(Counted(&drops)));
+            cell.drop_in_place();
+            assert_eq!(*drops.get(), 2);
+        }
+        cell.set(ManuallyDrop::new(Counted(&drops)));
+        cell.forget_value();
+        {
+            let _cell = cell;
+        }
+        assert_eq!(*drops.get(), 2);
+    }
+
+    #[test]
+    fn manual_cell_drops_once() {
+        let drops = SuperCell::new(0);
+        let cell = ManualCell::new(Counted(&drops));
+        drop(cell.take());
+        assert_eq!(*drops.get(), 1);
+        assert!(cell.try_take().is_none());
+
+        cell.set(Counted(&drops));
+        cell.set(Counted(&drops));
+        assert_eq!(*drops.get(), 2);
+        cell.drop_value();
+        assert_eq!(*drops.get(), 3);
+
+        cell.set(Counted(&drops));
+        cell.forget();
+        assert!(!cell.is_occupied());
+        cell.set(Counted(&drops));
+        drop(cell);
+        assert_eq!(*drops.get(), 4);
+        drop(ManualCell::<Counted<'_>>::empty());
+        assert_eq!(*drops.get(), 4);
+    }
+
+    #[test]
+    fn misuse_panics() {
+        let drops = SuperCell::new(0);
+        let cell = ManualCell::new(Counted(&drops));
+        cell.drop_value();
+        for misuse in [
+            &(|| drop(cell.take())) as &dyn Fn(),
+            &|| cell.drop_value(),
+            &|| cell.forget(),
+            &|| {
+                cell.get();
+            }
+        ] {
+            assert!(catch_unwind(AssertUnwindSafe(misuse)).is_err());
+        }
+        assert_eq!(*drops.get(), 1);
+    }
+}
@@ -0,0 +1,105 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+use crate::SuperCell;
+
+/// Helpers for map cells used as caches
+///
+/// References returned by these methods point into the map's storage. Any later insert can
+/// rehash the map and move every value, so a reference must not be used once another key has
+/// been inserted, copy or clone what is needed out of it first.
+impl<K : Eq + Hash, V, S : BuildHasher> SuperCell<HashMap<K, V, S>> {
+    /// Returns the value for `key`, inserting the result of `f` if it is missing
+    ///
+    /// `f` is only called on a miss.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use super_cell::SuperCell;
+    /// let cache = SuperCell::new(HashMap::new());
+    /// assert_eq!(*cache.get_or_insert_with("answer", || 42), 42);
+    /// assert_eq!(*cache.get_or_insert_with("answer", || unreachable!()), 42);
+    /// ```
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub fn get_or_insert_with(&self, key : K, f : impl FnOnce() -> V) -> &mut V {
+        self.get_mut().entry(key).or_insert_with(f)
+    }
+
+    /// Inserts `value` for `key`, returning the previous value
+    #[inline]
+    pub fn insert(&self, key : K, value : V) -> Option<V> {
+        self.get_mut().insert(key, value)
+    }
+
+    /// Removes `key`, returning its value
+    #[inline]
+    pub fn remove<Q : Eq + Hash + ?Sized>(&self, key : &Q) -> Option<V> where K : Borrow<Q> {
+        self.get_mut().remove(key)
+    }
+
+    /// Returns `true` if the map holds `key`
+    #[inline]
+    pub fn contains_key<Q : Eq + Hash + ?Sized>(&self, key : &Q) -> bool where K : Borrow<Q> {
+        self.get().contains_key(key)
+    }
+
+    /// Returns the number of entries in the map
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.get().len()
+    }
+
+    /// Returns `true` if the map has no entries
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.get().is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_hits_and_misses() {
+        let cache = SuperCell::new(HashMap::new());
+        let calls = SuperCell::new(0);
+        let square = |value : u64| *cache.get_or_insert_with(value, || {
+            *calls.get_mut() += 1;
+            value * value
+        });
+        assert_eq!(square(3), 9);
+        assert_eq!(square(4), 16);
+        assert_eq!(square(3), 9);
+        assert_eq!(*calls.get(), 2);
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let map = SuperCell::new(HashMap::new());
+        assert!(map.is_empty());
+        assert_eq!(map.insert(String::from("a"), 1), None);
+        assert_eq!(map.insert(String::from("a"), 2), Some(1));
+        assert!(map.contains_key("a"));
+        assert_eq!(map.remove("a"), Some(2));
+        assert_eq!(map.remove("a"), None);
+        assert!(!map.contains_key("a"));
+    }
+
+    #[test]
+    fn values_are_copied_out_before_inserting() {
+        // Holding `first` across the insert of a different key is not supported, the insert may
+        // rehash and move the value it points to. Copy the value out instead.
+        let map = SuperCell::new(HashMap::new());
+        let first = *map.get_or_insert_with(0, || 10);
+        for key in 1..64 {
+            map.insert(key, key * 10);
+        }
+        assert_eq!(first, 10);
+        assert_eq!(*map.get_or_insert_with(0, || unreachable!()), first);
+    }
+}
@@ -0,0 +1,245 @@
+use core::fmt::{Debug, Formatter};
+use core::num::{NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize};
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+#[allow(unused_imports)]
+use core::sync::atomic::*;
+use crate::SuperCell;
+
+mod sealed {
+    pub trait Sealed {
+        /// Zero sized type with the alignment of the atomic of the same width, which can be
+        /// stricter than the alignment of the integer, like for 64 bit integers on x86
+        type Align;
+    }
+
+    #[repr(align(2))]
+    pub struct Align2;
+    #[repr(align(4))]
+    pub struct Align4;
+    #[repr(align(8))]
+    pub struct Align8;
+    #[repr(align(16))]
+    pub struct Align16;
+
+    #[cfg(target_pointer_width = "16")]
+    pub type AlignPtr = Align2;
+    #[cfg(target_pointer_width = "32")]
+    pub type AlignPtr = Align4;
+    #[cfg(target_pointer_width = "64")]
+    pub type AlignPtr = Align8;
+}
+
+/// `NonZero` integers, whose `Option` is the plain integer with zero as `None`
+///
+/// Sealed, as [`NicheCell`] relies on that layout.
+pub trait NonZeroPrimitive : Copy + sealed::Sealed {}
+
+/// Optional `NonZero` integer stored as the plain integer, zero meaning empty
+///
+/// `NicheCell<NonZeroU32>` takes 4 bytes like a `u32`, which keeps dense arrays of optional
+/// indices small. Since the value is a plain integer, the cells of the sizes with atomics also
+/// have an atomic view for lock free optional slots. The cell is aligned like the atomic of the
+/// same width, so it may be more aligned than the integer, like `NicheCell<NonZeroU64>` on x86.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroU32;
+/// use super_cell::NicheCell;
+/// let parent = NicheCell::<NonZeroU32>::empty();
+/// let index = parent.get_or_insert_with(|| NonZeroU32::new(7).unwrap());
+/// assert_eq!(parent.get(), Some(index));
+/// assert_eq!(parent.take(), Some(index));
+/// assert_eq!(parent.get(), None);
+/// ```
+#[repr(C)]
+pub struct NicheCell<T : NonZeroPrimitive> {
+    _align : [T::Align; 0],
+    cell : SuperCell<Option<T>>
+}
+
+impl<T : NonZeroPrimitive> NicheCell<T> {
+    #[inline]
+    pub const fn new(value : T) -> Self {
+        Self { _align : [], cell : SuperCell::new(Some(value)) }
+    }
+
+    /// Creates an empty cell
+    #[inline]
+    pub const fn empty() -> Self {
+        Self { _align : [], cell : SuperCell::new(None) }
+    }
+
+    /// Returns the value, or `None` if the cell is empty
+    #[inline]
+    pub fn get(&self) -> Option<T> {
+        *self.cell.get()
+    }
+
+    /// Returns `true` if the cell holds a value
+    #[inline]
+    pub fn is_some(&self) -> bool {
+        self.get().is_some()
+    }
+
+    #[inline]
+    pub fn set(&self, value : T) {
+        self.cell.set(Some(value))
+    }
+
+    /// Empties the cell
+    #[inline]
+    pub fn clear(&self) {
+        self.cell.set(None)
+    }
+
+    /// Returns the value and empties the cell
+    #[inline]
+    pub fn take(&self) -> Option<T> {
+        self.cell.replace(None)
+    }
+
+    /// Sets the value, returning the previous one
+    #[inline]
+    pub fn replace(&self, value : T) -> Option<T> {
+        self.cell.replace(Some(value))
+    }
+
+    /// Returns the value, setting it to the result of `f` first if the cell is empty
+    #[inline]
+    pub fn get_or_insert_with(&self, f : impl FnOnce() -> T) -> T {
+        *self.cell.get_mut().get_or_insert_with(f)
+    }
+
+    /// Consumes the cell, returning the value
+    #[inline]
+    pub fn into_inner(self) -> Option<T> {
+        self.cell.into_inner()
+    }
+}
+
+impl<T : NonZeroPrimitive> Default for NicheCell<T> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T : NonZeroPrimitive> From<Option<T>> for NicheCell<T> {
+    fn from(value : Option<T>) -> Self {
+        Self { _align : [], cell : SuperCell::new(value) }
+    }
+}
+
+impl<T : NonZeroPrimitive + Debug> Debug for NicheCell<T> {
+    fn fmt(&self, f : &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("NicheCell").field(&self.get()).finish()
+    }
+}
+
+macro_rules! impl_non_zero {
+    ($($non_zero:ty => $align:ty),*) => {$(
+        impl sealed::Sealed for $non_zero {
+            type Align = $align;
+        }
+        impl NonZeroPrimitive for $non_zero {}
+    )*};
+}
+
+impl_non_zero!(
+    NonZeroU8 => (), NonZeroU16 => sealed::Align2, NonZeroU32 => sealed::Align4,
+    NonZeroU64 => sealed::Align8, NonZeroU128 => sealed::Align16, NonZeroUsize => sealed::AlignPtr,
+    NonZeroI8 => (), NonZeroI16 => sealed::Align2, NonZeroI32 => sealed::Align4,
+    NonZeroI64 => sealed::Align8, NonZeroI128 => sealed::Align16, NonZeroIsize => sealed::AlignPtr
+);
+
+macro_rules! impl_niche_atomics {
+    ($($width:literal => $non_zero:ty, $atomic:ty;)+) => {$(
+        #[cfg(target_has_atomic = $width)]
+        impl NicheCell<$non_zero> {
+            #[doc = concat!("Returns an `", stringify!($atomic), "` view of the value, `0` meaning empty")]
+            ///
+            /// Mixing atomic and plain access to the value at the same time is still a data race,
+            /// like for the atomic views of `SuperCell`.
+            #[inline]
+            pub fn as_atomic(&self) -> &$atomic {
+                const {
+                    assert!(size_of::<Option<$non_zero>>() == size_of::<$atomic>());
+                    assert!(align_of::<$atomic>() <= align_of::<Self>(), "atomic view needs a stricter alignment than the cell");
+                }
+                // SAFETY: `Option` of a `NonZero` integer has the layout of the integer, with `None`
+                // as zero, so every value of the atomic is a valid `Option<$non_zero>`.
+                unsafe { &*self.cell.as_ptr().cast::<$atomic>() }
+            }
+        }
+    )+};
+}
+
+impl_niche_atomics! {
+    "8" => NonZeroU8, AtomicU8;
+    "8" => NonZeroI8, AtomicI8;
+    "16" => NonZeroU16, AtomicU16;
+    "16" => NonZeroI16, AtomicI16;
+    "32" => NonZeroU32, AtomicU32;
+    "32" => NonZeroI32, AtomicI32;
+    "64" => NonZeroU64, AtomicU64;
+    "64" => NonZeroI64, AtomicI64;
+    "ptr" => NonZeroUsize, AtomicUsize;
+    "ptr" => NonZeroIsize, AtomicIsize;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use super::*;
+
+    fn index(value : u32) -> NonZeroU32 {
+        NonZeroU32::new(value).unwrap()
+    }
+
+    #[test]
+    fn same_size_as_integer() {
+        assert_eq!(size_of::<NicheCell<NonZeroU32>>(), 4);
+        assert_eq!(size_of::<NicheCell<NonZeroI8>>(), 1);
+        assert_eq!(size_of::<[NicheCell<NonZeroU64>; 4]>(), 32);
+        assert_eq!(align_of::<NicheCell<NonZeroU64>>(), 8);
+    }
+
+    #[test]
+    fn empty_and_full() {
+        let slot = NicheCell::default();
+        assert!(!slot.is_some());
+        assert_eq!(slot.take(), None);
+        slot.set(index(3));
+        assert_eq!(slot.get(), Some(index(3)));
+        assert_eq!(slot.get_or_insert_with(|| unreachable!()), index(3));
+        assert_eq!(slot.replace(index(4)), Some(index(3)));
+        slot.clear();
+        assert_eq!(slot.get(), None);
+        assert_eq!(slot.get_or_insert_with(|| index(5)), index(5));
+        assert_eq!(format!("{slot:?}"), "NicheCell(Some(5))");
+        assert_eq!(slot.into_inner(), Some(index(5)));
+        assert_eq!(NicheCell::from(Some(index(1))).take(), Some(index(1)));
+    }
+
+    #[test]
+    #[cfg(target_has_atomic = "32")]
+    fn concurrent_setters() {
+        let slots : Vec<NicheCell<NonZeroU32>> = (0..16).map(|_| NicheCell::empty()).collect();
+        thread::scope(|scope| {
+            for thread in 1..=4 {
+                let slots = &slots;
+                scope.spawn(move || {
+                    for slot in slots {
+                        let _ = slot.as_atomic().compare_exchange(0, thread, Ordering::AcqRel, Ordering::Acquire);
+                    }
+                });
+            }
+        });
+        for slot in &slots {
+            let value = slot.get().expect("every slot should have been claimed").get();
+            assert!((1..=4).contains(&value));
+        }
+        slots[0].as_atomic().store(0, Ordering::Relaxed);
+        assert_eq!(slots[0].get(), None);
+    }
+}
@@ -0,0 +1,111 @@
+use std::panic::resume_unwind;
+use std::thread;
+use std::vec::Vec;
+use crate::SuperCell;
+
+impl<T> SuperCell<[T]> {
+    /// Splits the slice into `num_threads` chunks and calls `f` with the index and cell of each
+    /// chunk, on one scoped thread per chunk
+    ///
+    /// Chunks are as even as possible, the first `len % num_threads` of them being one element
+    /// longer. Slices shorter than `num_threads` get one chunk per element, so no thread sees an
+    /// empty chunk, and empty slices get no call at all. Returns once every thread is done, and
+    /// resumes the panic of the first chunk that panicked, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_threads` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let mut values = vec![0; 10];
+    /// SuperCell::from_mut_slice(&mut values).par_update_chunks(3, |chunk, cell| {
+    ///     cell.with_mut(|values| values.fill(chunk));
+    /// });
+    /// assert_eq!(values, [0, 0, 0, 0, 1, 1, 1, 2, 2, 2]);
+    /// ```
+    #[track_caller]
+    pub fn par_update_chunks(&self, num_threads : usize, f : impl Fn(usize, &SuperCell<[T]>) + Sync) where T : Send + Sync {
+        assert!(num_threads != 0, "`num_threads` must not be 0");
+        let cells = self.as_slice_of_cells();
+        let chunks = num_threads.min(cells.len());
+        if chunks == 0 {
+            return;
+        }
+        let (size, longer) = (cells.len() / chunks, cells.len() % chunks);
+        let f = &f;
+        thread::scope(|scope| {
+            let mut start = 0;
+            let handles : Vec<_> = (0..chunks).map(|chunk| {
+                let end = start + size + usize::from(chunk < longer);
+                let cell = SuperCell::from_slice_of_cells(&cells[start..end]);
+                start = end;
+                scope.spawn(move || f(chunk, cell))
+            }).collect();
+            let mut panic = None;
+            for handle in handles {
+                if let Err(payload) = handle.join() {
+                    panic.get_or_insert(payload);
+                }
+            }
+            if let Some(payload) = panic {
+                resume_unwind(payload);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use super::*;
+
+    fn value(index : usize) -> u64 {
+        (index as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) >> 7
+    }
+
+    #[test]
+    fn matches_sequential() {
+        let len = if cfg!(miri) { 103 } else { 100_003 };
+        let reference : Vec<_> = (0..len).map(value).collect();
+        for threads in [1, 3, 8] {
+            let mut buffer = vec![0u64; reference.len()];
+            let slice = SuperCell::from_mut_slice(&mut buffer);
+            let lengths = SuperCell::new(vec![0; threads]);
+            slice.par_update_chunks(threads, |chunk, cells| {
+                let start = (cells.as_ptr().cast::<u64>() as usize - slice.as_ptr().cast::<u64>() as usize) / size_of::<u64>();
+                lengths.as_slice_of_cells()[chunk].set(cells.get().len());
+                cells.update_all(|index, cell| *cell = value(start + index));
+            });
+            assert_eq!(buffer, reference, "{threads} threads");
+            let lengths = lengths.into_inner();
+            assert!(lengths.windows(2).all(|pair| pair[0] == pair[1] || pair[0] == pair[1] + 1));
+        }
+    }
+
+    #[test]
+    fn short_slices() {
+        let mut values = [0usize; 3];
+        SuperCell::from_mut_slice(&mut values).par_update_chunks(8, |chunk, cells| {
+            assert_eq!(cells.get().len(), 1);
+            cells.as_slice_of_cells()[0].set(chunk + 1);
+        });
+        assert_eq!(values, [1, 2, 3]);
+        let empty : &mut [usize] = &mut [];
+        SuperCell::from_mut_slice(empty).par_update_chunks(4, |_, _| unreachable!());
+    }
+
+    #[test]
+    fn propagates_panics() {
+        let mut values = [0u8; 4];
+        let cells = SuperCell::from_mut_slice(&mut values);
+        let payload = catch_unwind(AssertUnwindSafe(|| cells.par_update_chunks(2, |chunk, _| {
+            if chunk == 1 {
+                panic!("chunk {chunk}");
+            }
+        }))).unwrap_err();
+        assert_eq!(payload.downcast_ref::<String>().map(String::as_str), Some("chunk 1"));
+    }
+}
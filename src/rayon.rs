@@ -0,0 +1,83 @@
+use rayon::prelude::*;
+use crate::SuperCell;
+
+/// Parallel iteration over the elements of a slice cell, yielding `&SuperCell<T>`
+///
+/// Unlike the cell itself, this requires `T : Send + Sync`, so values which are not thread
+/// safe cannot be handed to other threads this way.
+///
+/// # Examples
+///
+/// ```
+/// use rayon::prelude::*;
+/// use super_cell::SuperCell;
+/// let mut bodies = vec![1u64; 1000];
+/// let cell = SuperCell::from_mut_slice(&mut bodies);
+/// cell.into_par_iter().for_each(|body| *body.get_mut() *= 2);
+/// assert!(bodies.iter().all(|body| *body == 2));
+/// ```
+///
+/// ```compile_fail
+/// use std::rc::Rc;
+/// use rayon::prelude::*;
+/// use super_cell::SuperCell;
+/// let mut values = vec![Rc::new(1)];
+/// SuperCell::from_mut_slice(&mut values).into_par_iter().for_each(|value| drop(value.get().clone()));
+/// ```
+impl<'a, T : Send + Sync> IntoParallelIterator for &'a SuperCell<[T]> {
+    type Iter = rayon::slice::Iter<'a, SuperCell<T>>;
+    type Item = &'a SuperCell<T>;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_slice_of_cells().par_iter()
+    }
+}
+
+impl<T : Send + Sync> SuperCell<[T]> {
+    /// Returns a parallel iterator over chunks of `size` elements, the last chunk may be shorter
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use super_cell::SuperCell;
+    /// let mut values = vec![0; 10];
+    /// SuperCell::from_mut_slice(&mut values).par_chunks(4).enumerate().for_each(|(index, chunk)| {
+    ///     chunk.with_mut(|chunk| chunk.fill(index));
+    /// });
+    /// assert_eq!(values, vec![0, 0, 0, 0, 1, 1, 1, 1, 2, 2]);
+    /// ```
+    pub fn par_chunks(&self, size : usize) -> impl IndexedParallelIterator<Item = &SuperCell<[T]>> {
+        self.as_slice_of_cells().par_chunks(size).map(SuperCell::<T>::from_slice_of_cells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parallel_update() {
+        let mut values = vec![0u32; 1 << 16];
+        let cell = SuperCell::from_mut_slice(&mut values);
+        cell.into_par_iter().for_each(|value| *value.get_mut() += 1);
+        assert!(values.iter().all(|value| *value == 1));
+    }
+
+    #[test]
+    fn parallel_chunks() {
+        let mut values = vec![0usize; 1000];
+        let cell = SuperCell::from_mut_slice(&mut values);
+        cell.par_chunks(64).enumerate().for_each(|(index, chunk)| {
+            for (offset, value) in chunk.as_slice_of_cells().iter().enumerate() {
+                *value.get_mut() += index * 64 + offset;
+            }
+        });
+        assert_eq!(values, (0..1000).collect::<Vec<_>>());
+    }
+}
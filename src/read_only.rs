@@ -0,0 +1,136 @@
+use core::fmt::{Debug, Display, Formatter};
+use crate::SuperCell;
+
+/// Wrapper returned by [`SuperCell::read_only`] which only allows reading the value
+///
+/// Handing out this view instead of the `&SuperCell<T>` keeps mutation with the owner of the
+/// cell. It is as cheap to copy around as the reference it wraps.
+pub struct ReadOnlyCell<'a, T : ?Sized> {
+    cell : &'a SuperCell<T>
+}
+
+impl<T : ?Sized> SuperCell<T> {
+    /// Returns a view of this cell which only allows reading the value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::{ReadOnlyCell, SuperCell};
+    /// fn report(health : ReadOnlyCell<'_, u32>) -> String {
+    ///     format!("hp: {health}")
+    /// }
+    /// let health = SuperCell::new(10);
+    /// let view = health.read_only();
+    /// health.set(7);
+    /// assert_eq!(report(view), "hp: 7");
+    /// ```
+    #[inline]
+    pub fn read_only(&self) -> ReadOnlyCell<'_, T> {
+        ReadOnlyCell { cell : self }
+    }
+}
+
+impl<'a, T : ?Sized> ReadOnlyCell<'a, T> {
+    /// Returns a shared reference to the value
+    #[inline]
+    pub fn get(&self) -> &'a T {
+        self.cell.get()
+    }
+
+    /// Returns a view with a shorter lifetime
+    #[inline]
+    pub fn reborrow(&self) -> ReadOnlyCell<'_, T> {
+        *self
+    }
+}
+
+impl<T : Copy> ReadOnlyCell<'_, T> {
+    /// Returns a copy of the value
+    #[inline]
+    pub fn get_copied(&self) -> T {
+        *self.get()
+    }
+}
+
+impl<'a, T> ReadOnlyCell<'a, [T]> {
+    /// Returns a view of the element at `index`, or `None` if out of bounds
+    #[inline]
+    pub fn element(&self, index : usize) -> Option<ReadOnlyCell<'a, T>> {
+        self.cell.as_slice_of_cells().get(index).map(SuperCell::read_only)
+    }
+
+    /// Returns an iterator over views of the elements
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = ReadOnlyCell<'a, T>> + 'a {
+        self.cell.as_slice_of_cells().iter().map(SuperCell::read_only)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.get().len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.get().is_empty()
+    }
+}
+
+impl<T : ?Sized> Clone for ReadOnlyCell<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T : ?Sized> Copy for ReadOnlyCell<'_, T> {}
+
+impl<T : Debug + ?Sized> Debug for ReadOnlyCell<'_, T> {
+    fn fmt(&self, f : &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ReadOnlyCell").field(&self.get()).finish()
+    }
+}
+
+impl<T : Display + ?Sized> Display for ReadOnlyCell<'_, T> {
+    fn fmt(&self, f : &mut Formatter<'_>) -> core::fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Consumer side of the boundary, which can only read
+    struct Renderer<'a> {
+        positions : ReadOnlyCell<'a, [f32]>,
+        frame : ReadOnlyCell<'a, u64>
+    }
+
+    impl Renderer<'_> {
+        fn draw(&self) -> String {
+            let positions : Vec<_> = self.positions.iter().map(|position| position.get_copied().to_string()).collect();
+            format!("{}: {}", self.frame, positions.join(" "))
+        }
+    }
+
+    #[test]
+    fn producer_keeps_mutating() {
+        let mut buffer = [0.0f32; 3];
+        let positions = SuperCell::from_mut_slice(&mut buffer);
+        let frame = SuperCell::new(0u64);
+        let renderer = Renderer { positions : positions.read_only(), frame : frame.read_only() };
+        let mut frames = Vec::new();
+        for _ in 0..2 {
+            for position in positions.as_slice_of_cells() {
+                *position.get_mut() += 0.5;
+            }
+            *frame.get_mut() += 1;
+            frames.push(renderer.draw());
+        }
+        assert_eq!(frames, ["1: 0.5 0.5 0.5", "2: 1 1 1"]);
+        assert_eq!(renderer.positions.len(), 3);
+        assert_eq!(renderer.positions.element(1).map(|position| position.get_copied()), Some(1.0));
+        assert!(renderer.positions.element(3).is_none());
+        assert_eq!(format!("{:?}", renderer.frame.reborrow()), "ReadOnlyCell(2)");
+    }
+}
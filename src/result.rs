@@ -0,0 +1,103 @@
+use crate::SuperCell;
+
+/// Helpers for result slots, where `None` means no result has been produced yet
+impl<T, E> SuperCell<Option<Result<T, E>>> {
+    /// Returns `true` if the slot holds an `Ok` result
+    #[inline]
+    pub fn is_ok(&self) -> bool {
+        matches!(self.get(), Some(Ok(_)))
+    }
+
+    /// Returns `true` if the slot holds an `Err` result
+    #[inline]
+    pub fn is_err(&self) -> bool {
+        matches!(self.get(), Some(Err(_)))
+    }
+
+    /// Returns `true` if no result has been produced yet
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.get().is_none()
+    }
+
+    /// Takes the result out of the slot, leaving it empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let slot : SuperCell<Option<Result<u32, String>>> = SuperCell::new(None);
+    /// slot.set_ok(10);
+    /// assert_eq!(slot.take_result(), Some(Ok(10)));
+    /// assert!(slot.is_empty());
+    /// ```
+    #[inline]
+    pub fn take_result(&self) -> Option<Result<T, E>> {
+        self.get_mut().take()
+    }
+
+    /// Takes the value out of the slot if it holds an `Ok` result, otherwise leaves it untouched
+    pub fn take_ok(&self) -> Option<T> {
+        self.get_mut().take_if(|result| result.is_ok()).and_then(Result::ok)
+    }
+
+    /// Stores an `Ok` result, dropping any previous result
+    #[inline]
+    pub fn set_ok(&self, value : T) {
+        *self.get_mut() = Some(Ok(value));
+    }
+
+    /// Stores an `Err` result, dropping any previous result
+    #[inline]
+    pub fn set_err(&self, error : E) {
+        *self.get_mut() = Some(Err(error));
+    }
+
+    /// Transforms the error in place if the slot holds an `Err` result
+    ///
+    /// If `f` panics, the slot is left empty.
+    pub fn map_err_in_place(&self, f : impl FnOnce(E) -> E) {
+        let slot = self.get_mut();
+        if let Some(Err(error)) = slot.take_if(|result| result.is_err()) {
+            *slot = Some(Err(f(error)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use super::*;
+
+    type Slot = SuperCell<Option<Result<u32, String>>>;
+
+    #[test]
+    fn states() {
+        let slot = Slot::new(None);
+        assert!(slot.is_empty() && !slot.is_ok() && !slot.is_err());
+        assert_eq!(slot.take_ok(), None);
+
+        slot.set_err(String::from("failed"));
+        assert!(slot.is_err());
+        slot.map_err_in_place(|error| error + " twice");
+        assert_eq!(slot.take_ok(), None);
+        assert_eq!(slot.take_result(), Some(Err(String::from("failed twice"))));
+
+        slot.set_ok(5);
+        assert!(slot.is_ok());
+        slot.map_err_in_place(|_| unreachable!());
+        assert_eq!(slot.take_ok(), Some(5));
+        assert!(slot.is_empty());
+    }
+
+    #[test]
+    fn handoff() {
+        let slot = Slot::new(None);
+        thread::scope(|scope| {
+            scope.spawn(|| slot.set_ok(42)).join().expect("Failed to join thread!");
+            let consumer = scope.spawn(|| slot.take_result());
+            assert_eq!(consumer.join().expect("Failed to join thread!"), Some(Ok(42)));
+        });
+        assert!(slot.is_empty());
+    }
+}
@@ -0,0 +1,98 @@
+use crate::SuperCell;
+
+/// Guard returned by [`SuperCell::scoped_set`] which writes the displaced value back into the
+/// cell when dropped, including during unwinding.
+///
+/// Nested overrides restore in LIFO order, as each guard holds the value it displaced.
+#[must_use = "the override is reverted as soon as the guard is dropped"]
+pub struct ScopedOverride<'a, T> {
+    cell : &'a SuperCell<T>,
+    previous : Option<T>
+}
+
+impl<T> SuperCell<T> {
+    /// Temporarily replaces the value, restoring the previous one when the guard drops.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// static LEVEL : SuperCell<u8> = SuperCell::new(1);
+    /// {
+    ///     let _guard = LEVEL.scoped_set(3);
+    ///     assert_eq!(*LEVEL.get(), 3);
+    /// }
+    /// assert_eq!(*LEVEL.get(), 1);
+    /// ```
+    pub fn scoped_set(&self, value : T) -> ScopedOverride<'_, T> {
+        ScopedOverride {
+            cell : self,
+            previous : Some(self.replace(value))
+        }
+    }
+}
+
+impl<T> ScopedOverride<'_, T> {
+    /// Keeps the override in place, returning the value it displaced.
+    pub fn leak(mut self) -> T {
+        self.previous.take().expect("override already restored")
+    }
+}
+
+impl<T> Drop for ScopedOverride<'_, T> {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            self.cell.set(previous);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use super::*;
+
+    #[test]
+    fn restores_on_drop() {
+        let cell = SuperCell::new(String::from("default"));
+        {
+            let _guard = cell.scoped_set(String::from("override"));
+            assert_eq!(cell.get(), "override");
+        }
+        assert_eq!(cell.get(), "default");
+    }
+
+    #[test]
+    fn restores_on_unwind() {
+        static CONFIG : SuperCell<usize> = SuperCell::new(1);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let _guard = CONFIG.scoped_set(2);
+            assert_eq!(*CONFIG.get(), 2);
+            panic!("failure while overridden");
+        }));
+        assert!(result.is_err());
+        assert_eq!(*CONFIG.get(), 1);
+    }
+
+    #[test]
+    fn nested_overrides() {
+        let cell = SuperCell::new(1);
+        {
+            let _outer = cell.scoped_set(2);
+            {
+                let _inner = cell.scoped_set(3);
+                assert_eq!(*cell.get(), 3);
+            }
+            assert_eq!(*cell.get(), 2);
+        }
+        assert_eq!(*cell.get(), 1);
+    }
+
+    #[test]
+    fn leaked_override() {
+        let cell = SuperCell::new(1);
+        let previous = cell.scoped_set(2).leak();
+        assert_eq!(previous, 1);
+        assert_eq!(*cell.get(), 2);
+    }
+}
@@ -0,0 +1,160 @@
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+use crate::SuperCell;
+
+/// `SuperCell` which zeroizes its value when dropped, for key material and other secrets
+///
+/// Unlike the other wrappers this does not dereference to the inner `SuperCell`, whose `set` and
+/// `replace` would drop or hand out the old secret without zeroizing it. The wrappers here
+/// zeroize every value they displace.
+///
+/// # Examples
+///
+/// ```
+/// use super_cell::SecretCell;
+/// let key = SecretCell::new([7u8; 32]);
+/// key.get_mut()[0] = 1;
+/// let old = key.replace([9; 32]);
+/// assert_eq!(old[0], 1);
+/// key.zeroize_now();
+/// assert_eq!(*key.get(), [0; 32]);
+/// ```
+#[repr(transparent)]
+pub struct SecretCell<T : Zeroize> {
+    cell : SuperCell<T>
+}
+
+impl<T : Zeroize> SecretCell<T> {
+    #[inline]
+    pub const fn new(value : T) -> Self {
+        Self {
+            cell : SuperCell::new(value)
+        }
+    }
+
+    #[inline]
+    pub fn get(&self) -> &T {
+        self.cell.get()
+    }
+
+    #[inline]
+    pub fn get_mut(&self) -> &mut T {
+        self.cell.get_mut()
+    }
+
+    #[inline]
+    pub fn as_ptr(&self) -> *mut T {
+        self.cell.as_ptr()
+    }
+
+    /// Sets the value, zeroizing the old one before dropping it
+    #[inline]
+    pub fn set(&self, value : T) {
+        drop(self.replace(value))
+    }
+
+    /// Sets the value, returning the old one wrapped so it is zeroized when dropped
+    #[inline]
+    pub fn replace(&self, value : T) -> Zeroizing<T> {
+        Zeroizing::new(self.cell.replace(value))
+    }
+
+    /// Zeroizes the value through a shared reference
+    #[inline]
+    pub fn zeroize_now(&self) {
+        self.cell.zeroize_now()
+    }
+}
+
+impl<T : Zeroize + ?Sized> SuperCell<T> {
+    /// Zeroizes the value through a shared reference
+    #[inline]
+    pub fn zeroize_now(&self) {
+        self.get_mut().zeroize()
+    }
+}
+
+impl<T : Zeroize + ?Sized> Zeroize for SuperCell<T> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.value.get_mut().zeroize()
+    }
+}
+
+impl<T : Zeroize> Drop for SecretCell<T> {
+    fn drop(&mut self) {
+        self.cell.zeroize()
+    }
+}
+
+impl<T : Zeroize> ZeroizeOnDrop for SecretCell<T> {}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::ManuallyDrop;
+    use core::ptr;
+    use super::*;
+
+    /// Logs its bytes when dropped, to see whether they were zeroized first
+    struct Probe<'a> {
+        bytes : [u8; 4],
+        log : &'a SuperCell<Vec<[u8; 4]>>
+    }
+
+    impl Zeroize for Probe<'_> {
+        fn zeroize(&mut self) {
+            self.bytes.zeroize()
+        }
+    }
+
+    impl Drop for Probe<'_> {
+        fn drop(&mut self) {
+            self.log.get_mut().push(self.bytes);
+        }
+    }
+
+    #[test]
+    fn zeroize_buffers() {
+        let mut key = [0xAAu8; 32];
+        let slice = SuperCell::from_mut_slice(&mut key);
+        slice.zeroize_now();
+        assert!(slice.get().iter().all(|&byte| byte == 0));
+
+        let mut buffer = SuperCell::new(vec![1u8, 2, 3]);
+        buffer.zeroize();
+        assert!(buffer.get().is_empty());
+        buffer.get_mut().push(4);
+        buffer.zeroize_now();
+        assert!(buffer.get().is_empty());
+    }
+
+    #[test]
+    fn secret_zeroizes_on_drop() {
+        let mut secret = ManuallyDrop::new(SecretCell::new([0x55u8; 16]));
+        let slot : *mut SecretCell<[u8; 16]> = &mut *secret;
+        // SAFETY: `secret` is never dropped again, and its storage outlives both accesses. The
+        // cell is `repr(transparent)` over the bytes.
+        unsafe {
+            ptr::drop_in_place(slot);
+            assert_eq!(slot.cast::<[u8; 16]>().read(), [0; 16]);
+        }
+    }
+
+    #[test]
+    fn displaced_values_zeroized() {
+        let log = SuperCell::new(Vec::new());
+        let secret = SecretCell::new(Probe { bytes : [1; 4], log : &log });
+        secret.set(Probe { bytes : [2; 4], log : &log });
+        let old = secret.replace(Probe { bytes : [3; 4], log : &log });
+        assert_eq!(old.bytes, [2; 4]);
+        drop(old);
+        assert_eq!(*log.get(), [[0; 4], [0; 4]]);
+        assert_eq!(secret.get().bytes, [3; 4]);
+    }
+
+    #[test]
+    fn zeroized_before_deallocation() {
+        let log = SuperCell::new(Vec::new());
+        drop(SecretCell::new(Probe { bytes : [9; 4], log : &log }));
+        assert_eq!(*log.get(), [[0; 4]]);
+    }
+}
@@ -0,0 +1,169 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::aligned::{Alignment, SupportedAlignment};
+use crate::{AlignedCell, FrozenCell, LabeledCell, ManualCell, NicheCell, NonZeroPrimitive, ReadOnlyCell};
+
+impl<T : Serialize + ?Sized> Serialize for FrozenCell<T> {
+    fn serialize<S : Serializer>(&self, serializer : S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de, T : Deserialize<'de>> Deserialize<'de> for FrozenCell<T> {
+    fn deserialize<D : Deserializer<'de>>(deserializer : D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(FrozenCell::new)
+    }
+}
+
+impl<T : Serialize + ?Sized, const ALIGN : usize> Serialize for AlignedCell<T, ALIGN> where Alignment<ALIGN> : SupportedAlignment {
+    fn serialize<S : Serializer>(&self, serializer : S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de, T : Deserialize<'de>, const ALIGN : usize> Deserialize<'de> for AlignedCell<T, ALIGN> where Alignment<ALIGN> : SupportedAlignment {
+    fn deserialize<D : Deserializer<'de>>(deserializer : D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(AlignedCell::new)
+    }
+}
+
+impl<T : Serialize> Serialize for ManualCell<T> {
+    fn serialize<S : Serializer>(&self, serializer : S) -> Result<S::Ok, S::Error> {
+        self.is_occupied().then(|| self.get()).serialize(serializer)
+    }
+}
+
+impl<'de, T : Deserialize<'de>> Deserialize<'de> for ManualCell<T> {
+    fn deserialize<D : Deserializer<'de>>(deserializer : D) -> Result<Self, D::Error> {
+        Ok(Option::<T>::deserialize(deserializer)?.map_or_else(ManualCell::empty, ManualCell::new))
+    }
+}
+
+impl<T : NonZeroPrimitive + Serialize> Serialize for NicheCell<T> {
+    fn serialize<S : Serializer>(&self, serializer : S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de, T : NonZeroPrimitive + Deserialize<'de>> Deserialize<'de> for NicheCell<T> {
+    fn deserialize<D : Deserializer<'de>>(deserializer : D) -> Result<Self, D::Error> {
+        Option::<T>::deserialize(deserializer).map(NicheCell::from)
+    }
+}
+
+#[cfg(feature = "critical-section")]
+impl<T : Serialize> Serialize for crate::CriticalCell<T> {
+    fn serialize<S : Serializer>(&self, serializer : S) -> Result<S::Ok, S::Error> {
+        self.with(|value| value.serialize(serializer))
+    }
+}
+
+#[cfg(feature = "critical-section")]
+impl<'de, T : Deserialize<'de>> Deserialize<'de> for crate::CriticalCell<T> {
+    fn deserialize<D : Deserializer<'de>>(deserializer : D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(crate::CriticalCell::new)
+    }
+}
+
+impl<T : Serialize + ?Sized> Serialize for ReadOnlyCell<'_, T> {
+    fn serialize<S : Serializer>(&self, serializer : S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<T : Serialize + ?Sized> Serialize for LabeledCell<'_, T> {
+    fn serialize<S : Serializer>(&self, serializer : S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroU16;
+    use serde::{Deserialize, Serialize};
+    use crate::SuperCell;
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Snapshot {
+        plain : SuperCell<u32>,
+        frozen : FrozenCell<String>,
+        aligned : AlignedCell<[u8; 2], 64>,
+        occupied : ManualCell<i8>,
+        empty : ManualCell<i8>,
+        parent : NicheCell<NonZeroU16>,
+        root : NicheCell<NonZeroU16>
+    }
+
+    /// Pins the wire formats, changing them breaks stored data
+    const JSON : &str = r#"{"plain":1,"frozen":"key","aligned":[2,3],"occupied":-4,"empty":null,"parent":300,"root":null}"#;
+    const POSTCARD : &[u8] = &[1, 3, b'k', b'e', b'y', 2, 3, 1, 0xFC, 0, 1, 0xAC, 0x02, 0];
+
+    fn snapshot() -> Snapshot {
+        Snapshot {
+            plain : SuperCell::new(1),
+            frozen : FrozenCell::new(String::from("key")),
+            aligned : AlignedCell::new([2, 3]),
+            occupied : ManualCell::new(-4),
+            empty : ManualCell::empty(),
+            parent : NicheCell::new(NonZeroU16::new(300).unwrap()),
+            root : NicheCell::empty()
+        }
+    }
+
+    fn assert_restored(snapshot : &Snapshot) {
+        assert_eq!(*snapshot.plain.get(), 1);
+        assert_eq!(snapshot.frozen.get(), "key");
+        assert_eq!(*snapshot.aligned.get(), [2, 3]);
+        assert_eq!(snapshot.aligned.as_ptr() as usize % 64, 0);
+        assert_eq!(*snapshot.occupied.get(), -4);
+        assert!(!snapshot.empty.is_occupied());
+        assert_eq!(snapshot.parent.get(), NonZeroU16::new(300));
+        assert_eq!(snapshot.root.get(), None);
+    }
+
+    #[test]
+    fn companions_as_values() {
+        assert_eq!(serde_json::to_string(&snapshot()).unwrap(), JSON);
+        let restored : Snapshot = serde_json::from_str(JSON).unwrap();
+        assert_restored(&restored);
+        assert_eq!(serde_json::to_string(&restored).unwrap(), JSON);
+    }
+
+    #[test]
+    fn companions_in_binary() {
+        assert_eq!(postcard::to_allocvec(&snapshot()).unwrap(), POSTCARD);
+        let restored : Snapshot = postcard::from_bytes(POSTCARD).unwrap();
+        assert_restored(&restored);
+        assert_eq!(postcard::to_allocvec(&restored).unwrap(), POSTCARD);
+    }
+
+    #[test]
+    fn zero_niche_rejected() {
+        assert!(serde_json::from_str::<NicheCell<NonZeroU16>>("0").is_err());
+        assert!(postcard::from_bytes::<NicheCell<NonZeroU16>>(&[1, 0]).is_err());
+    }
+
+    #[test]
+    fn views_as_values() {
+        let cell = SuperCell::new(vec![1, 2]);
+        assert_eq!(serde_json::to_string(&cell.read_only()).unwrap(), "[1,2]");
+        assert_eq!(serde_json::to_string(&cell.labeled("serde.views")).unwrap(), "[1,2]");
+        // Signed integers are zigzag encoded after the length.
+        assert_eq!(postcard::to_allocvec(&cell.read_only()).unwrap(), [2, 2, 4]);
+        assert_eq!(postcard::to_allocvec(&cell.labeled("serde.views")).unwrap(), [2, 2, 4]);
+    }
+
+    #[test]
+    #[cfg(feature = "critical-section")]
+    fn critical_as_value() {
+        let cell = crate::CriticalCell::new((1u8, 'a'));
+        let json = serde_json::to_string(&cell).unwrap();
+        assert_eq!(json, r#"[1,"a"]"#);
+        let mut cell : crate::CriticalCell<(u8, char)> = serde_json::from_str(&json).unwrap();
+        assert_eq!(*cell.get_mut(), (1, 'a'));
+        let bytes = postcard::to_allocvec(&cell).unwrap();
+        assert_eq!(bytes, [1, 1, b'a']);
+        let mut cell : crate::CriticalCell<(u8, char)> = postcard::from_bytes(&bytes).unwrap();
+        assert_eq!(*cell.get_mut(), (1, 'a'));
+    }
+}
@@ -0,0 +1,81 @@
+use alloc::sync::{Arc, Weak};
+use alloc::vec::Vec;
+use crate::SuperCell;
+
+/// Owning handle to a `SuperCell` shared between threads
+pub type SharedSuperCell<T> = Arc<SuperCell<T>>;
+
+impl<T> SuperCell<T> {
+    /// Creates a new `SharedSuperCell` containing `value`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use std::thread;
+    /// use super_cell::SuperCell;
+    /// let shared = SuperCell::new_shared(0);
+    /// let other = Arc::clone(&shared);
+    /// thread::spawn(move || *other.get_mut() = 1).join().unwrap();
+    /// assert_eq!(*shared.get(), 1);
+    /// ```
+    pub fn new_shared(value : T) -> SharedSuperCell<T> {
+        Arc::new(SuperCell::new(value))
+    }
+
+    /// Creates a new `SharedSuperCell` whose value can hold a weak reference to itself,
+    /// delegating to `Arc::new_cyclic`.
+    pub fn new_shared_cyclic(f : impl FnOnce(&Weak<SuperCell<T>>) -> T) -> SharedSuperCell<T> {
+        Arc::new_cyclic(|weak| SuperCell::new(f(weak)))
+    }
+
+    /// Creates a shared slice of cells from a `Vec<T>`
+    pub fn shared_from_vec(vec : Vec<T>) -> Arc<[SuperCell<T>]> {
+        Arc::from(SuperCell::wrap_vec(vec))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use super::*;
+
+    #[test]
+    fn shared_between_threads() {
+        let shared = SuperCell::new_shared(vec![0usize; 4]);
+        let handles : Vec<_> = (0..4).map(|index| {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || shared.as_slice_of_cells()[index].set(index + 1))
+        }).collect();
+        for handle in handles {
+            handle.join().expect("Failed to join thread!");
+        }
+        assert_eq!(*shared.get(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn shared_slice_between_threads() {
+        let shared = SuperCell::shared_from_vec(vec![0usize; 4]);
+        let handles : Vec<_> = (0..4).map(|index| {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || *shared[index].get_mut() = index * 10)
+        }).collect();
+        for handle in handles {
+            handle.join().expect("Failed to join thread!");
+        }
+        assert_eq!(shared.iter().map(|cell| *cell.get()).collect::<Vec<_>>(), vec![0, 10, 20, 30]);
+    }
+
+    #[test]
+    fn shared_cyclic() {
+        struct Node {
+            this : Weak<SuperCell<Node>>,
+            value : usize
+        }
+        let shared = SuperCell::new_shared_cyclic(|weak| Node { this: weak.clone(), value: 1 });
+        let this = shared.get().this.upgrade().expect("Node should be alive");
+        let handle = thread::spawn(move || this.get_mut().value = 2);
+        handle.join().expect("Failed to join thread!");
+        assert_eq!(shared.get().value, 2);
+    }
+}
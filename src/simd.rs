@@ -0,0 +1,156 @@
+use wide::{f32x8, f64x4, i32x8, i64x4, u32x8, u64x4};
+use crate::SuperCell;
+
+/// Implements the bulk numeric operations for slices of `$ty`, processed `$lanes` values at a
+/// time as `$simd`, with `$add` and `$mul` used for the remaining values
+macro_rules! impl_simd {
+    ($($ty:ty => $simd:ident, $lanes:literal, $add:expr, $mul:expr;)*) => {$(
+        impl SuperCell<[$ty]> {
+            /// Adds `x` to every value
+            pub fn add_assign_scalar(&self, x : $ty) {
+                let (chunks, tail) = self.get_mut().as_chunks_mut::<$lanes>();
+                let splat = $simd::splat(x);
+                for chunk in chunks {
+                    *chunk = ($simd::from(*chunk) + splat).to_array();
+                }
+                for value in tail {
+                    *value = $add(*value, x);
+                }
+            }
+
+            /// Multiplies every value by `x`
+            pub fn mul_assign_scalar(&self, x : $ty) {
+                let (chunks, tail) = self.get_mut().as_chunks_mut::<$lanes>();
+                let splat = $simd::splat(x);
+                for chunk in chunks {
+                    *chunk = ($simd::from(*chunk) * splat).to_array();
+                }
+                for value in tail {
+                    *value = $mul(*value, x);
+                }
+            }
+
+            /// Adds each value of `other` to the value at the same index
+            ///
+            /// # Panics
+            ///
+            /// Panics if the lengths differ.
+            #[track_caller]
+            pub fn add_assign_slice(&self, other : &[$ty]) {
+                let values = self.get_mut();
+                assert_eq!(values.len(), other.len(), "slices must have the same length");
+                let (chunks, tail) = values.as_chunks_mut::<$lanes>();
+                let (other_chunks, other_tail) = other.as_chunks::<$lanes>();
+                for (chunk, other) in chunks.iter_mut().zip(other_chunks) {
+                    *chunk = ($simd::from(*chunk) + $simd::from(*other)).to_array();
+                }
+                for (value, other) in tail.iter_mut().zip(other_tail) {
+                    *value = $add(*value, *other);
+                }
+            }
+
+            /// Returns the sum of the products of the values at the same index
+            ///
+            /// # Panics
+            ///
+            /// Panics if the lengths differ.
+            #[track_caller]
+            pub fn dot(&self, other : &[$ty]) -> $ty {
+                let values = self.get();
+                assert_eq!(values.len(), other.len(), "slices must have the same length");
+                let (chunks, tail) = values.as_chunks::<$lanes>();
+                let (other_chunks, other_tail) = other.as_chunks::<$lanes>();
+                let mut sum = $simd::splat(0 as $ty);
+                for (chunk, other) in chunks.iter().zip(other_chunks) {
+                    sum += $simd::from(*chunk) * $simd::from(*other);
+                }
+                tail.iter().zip(other_tail).fold(sum.reduce_add(), |sum, (value, other)| $add(sum, $mul(*value, *other)))
+            }
+
+            /// Returns the sum of the values
+            pub fn sum(&self) -> $ty {
+                let (chunks, tail) = self.get().as_chunks::<$lanes>();
+                let mut sum = $simd::splat(0 as $ty);
+                for chunk in chunks {
+                    sum += $simd::from(*chunk);
+                }
+                tail.iter().fold(sum.reduce_add(), |sum, value| $add(sum, *value))
+            }
+        }
+    )*};
+}
+
+impl_simd! {
+    f32 => f32x8, 8, |a, b| a + b, |a, b| a * b;
+    f64 => f64x4, 4, |a, b| a + b, |a, b| a * b;
+    i32 => i32x8, 8, i32::wrapping_add, i32::wrapping_mul;
+    u32 => u32x8, 8, u32::wrapping_add, u32::wrapping_mul;
+    i64 => i64x4, 4, i64::wrapping_add, i64::wrapping_mul;
+    u64 => u64x4, 4, u64::wrapping_add, u64::wrapping_mul;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LENGTHS : [usize; 6] = [0, 1, 7, 8, 9, 1003];
+
+    fn floats(len : usize, seed : f32) -> Vec<f32> {
+        (0..len).map(|index| (index as f32 * seed).sin()).collect()
+    }
+
+    fn close(a : f64, b : f64) -> bool {
+        (a - b).abs() <= 1e-3 * (1.0 + b.abs())
+    }
+
+    #[test]
+    fn floats_match_scalar() {
+        for len in LENGTHS {
+            let (a, b) = (floats(len, 0.7), floats(len, 1.3));
+            let cell = SuperCell::new(a.clone());
+            let slice = SuperCell::from_mut_slice(cell.get_mut());
+            slice.add_assign_scalar(1.5);
+            slice.mul_assign_scalar(-2.0);
+            slice.add_assign_slice(&b);
+            let expected : Vec<_> = a.iter().zip(&b).map(|(a, b)| (a + 1.5) * -2.0 + b).collect();
+            assert_eq!(*slice.get(), *expected, "length {len}");
+            assert!(close(slice.dot(&b) as f64, expected.iter().zip(&b).map(|(a, b)| a * b).sum::<f32>() as f64));
+            assert!(close(slice.sum() as f64, expected.iter().sum::<f32>() as f64));
+
+            let doubles : Vec<f64> = a.iter().map(|&value| value as f64).collect();
+            let cell = SuperCell::new(doubles.clone());
+            let slice = SuperCell::from_mut_slice(cell.get_mut());
+            slice.mul_assign_scalar(3.0);
+            assert!(close(slice.sum(), doubles.iter().map(|value| value * 3.0).sum()));
+        }
+    }
+
+    #[test]
+    fn integers_match_scalar() {
+        for len in LENGTHS {
+            let a : Vec<i32> = (0..len as i32).map(|value| value * 7 - 300).collect();
+            let b : Vec<i32> = (0..len as i32).map(|value| 5 - value).collect();
+            let mut values = a.clone();
+            let slice = SuperCell::from_mut_slice(&mut values);
+            slice.add_assign_scalar(3);
+            slice.mul_assign_scalar(2);
+            slice.add_assign_slice(&b);
+            let expected : Vec<_> = a.iter().zip(&b).map(|(a, b)| (a + 3) * 2 + b).collect();
+            assert_eq!(slice.dot(&b), expected.iter().zip(&b).fold(0i32, |sum, (a, b)| sum.wrapping_add(a.wrapping_mul(*b))));
+            assert_eq!(slice.sum(), expected.iter().sum::<i32>());
+            assert_eq!(values, expected);
+
+            let mut unsigned : Vec<u64> = (0..len as u64).collect();
+            let slice = SuperCell::from_mut_slice(&mut unsigned);
+            slice.add_assign_scalar(u64::MAX);
+            assert_eq!(slice.sum(), (0..len as u64).fold(0u64, |sum, value| sum.wrapping_add(value.wrapping_add(u64::MAX))));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "slices must have the same length")]
+    fn length_mismatch() {
+        let mut values = [1.0f32; 9];
+        SuperCell::from_mut_slice(&mut values).add_assign_slice(&[1.0; 8]);
+    }
+}
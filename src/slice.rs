@@ -0,0 +1,423 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::Range;
+use core::ptr;
+use crate::SuperCell;
+
+/// Aborts the process if dropped during unwinding, by panicking again.
+struct AbortOnUnwind;
+
+impl Drop for AbortOnUnwind {
+    fn drop(&mut self) {
+        panic!("closure panicked while a value was moved out of the cell, aborting");
+    }
+}
+
+impl<T> SuperCell<[T]> {
+    /// Returns the cell of the element at `index`, without bounds checking
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than the length of the slice, which is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let mut values = [1, 2, 3];
+    /// let cell = SuperCell::from_mut_slice(&mut values);
+    /// // SAFETY: 2 is in bounds.
+    /// unsafe { *cell.get_unchecked(2).get_mut() = 4 };
+    /// assert_eq!(values, [1, 2, 4]);
+    /// ```
+    #[inline]
+    pub unsafe fn get_unchecked(&self, index : usize) -> &SuperCell<T> {
+        debug_assert!(index < self.as_ptr().len(), "index out of bounds");
+        // SAFETY: The caller guarantees that `index` is in bounds.
+        unsafe { &*self.as_ptr().cast::<T>().add(index).cast::<SuperCell<T>>() }
+    }
+
+    /// Returns the cell of the elements in `range`, without bounds checking
+    ///
+    /// # Safety
+    ///
+    /// `range.start` must not be greater than `range.end`, and `range.end` must not be greater
+    /// than the length of the slice, which is checked in debug builds.
+    #[inline]
+    pub unsafe fn get_unchecked_range(&self, range : Range<usize>) -> &SuperCell<[T]> {
+        debug_assert!(range.start <= range.end && range.end <= self.as_ptr().len(), "range out of bounds");
+        // SAFETY: The caller guarantees that `range` is in bounds.
+        unsafe {
+            let start = self.as_ptr().cast::<T>().add(range.start);
+            &*(ptr::slice_from_raw_parts_mut(start, range.end - range.start) as *const SuperCell<[T]>)
+        }
+    }
+
+    /// Calls `f` with the index and a mutable reference of every element
+    ///
+    /// Equivalent to looping over [`SuperCell::as_slice_of_cells`], but the slice is only
+    /// borrowed once, so the optimizer sees a plain loop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let mut values = [1, 2, 3];
+    /// SuperCell::from_mut_slice(&mut values).update_all(|index, value| *value += index);
+    /// assert_eq!(values, [1, 3, 5]);
+    /// ```
+    #[inline]
+    pub fn update_all<F : FnMut(usize, &mut T)>(&self, mut f : F) {
+        for (index, value) in self.get_mut().iter_mut().enumerate() {
+            f(index, value);
+        }
+    }
+
+    /// Replaces every element with the result of calling `f` on it
+    ///
+    /// # Panics
+    ///
+    /// Each element is moved out while `f` runs, so if `f` panics the process aborts rather
+    /// than leaving a moved out element behind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let mut values = [String::from("a"), String::from("b")];
+    /// SuperCell::from_mut_slice(&mut values).map_in_place(|value| value + "!");
+    /// assert_eq!(values, ["a!", "b!"]);
+    /// ```
+    pub fn map_in_place<F : FnMut(T) -> T>(&self, mut f : F) {
+        let guard = AbortOnUnwind;
+        for value in self.get_mut().iter_mut() {
+            let value : *mut T = value;
+            // SAFETY: The value is written back before moving on, and a panic in between aborts.
+            unsafe { value.write(f(value.read())) }
+        }
+        core::mem::forget(guard);
+    }
+
+    /// Moves every element out into a `Vec`, leaving `T::default()` in its place
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let mut batch = [vec![1], vec![2, 3]];
+    /// let taken = SuperCell::from_mut_slice(&mut batch).take_all();
+    /// assert_eq!(taken, [vec![1], vec![2, 3]]);
+    /// assert!(batch.iter().all(Vec::is_empty));
+    /// ```
+    pub fn take_all(&self) -> Vec<T> where T : Default {
+        self.replace_all(|_| T::default())
+    }
+
+    /// Moves every element out into a `Vec`, leaving `replacement(index)` in its place
+    ///
+    /// Every replacement is built before any element is moved, so if `replacement` panics the
+    /// slice is left untouched and the replacements built so far are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let mut slots = [String::from("a"), String::from("b")];
+    /// let old = SuperCell::from_mut_slice(&mut slots).replace_all(|index| index.to_string());
+    /// assert_eq!(old, ["a", "b"]);
+    /// assert_eq!(slots, ["0", "1"]);
+    /// ```
+    pub fn replace_all(&self, replacement : impl FnMut(usize) -> T) -> Vec<T> {
+        let mut values : Vec<T> = (0..self.as_ptr().len()).map(replacement).collect();
+        self.swap_with_slice(&mut values);
+        values
+    }
+
+    /// Swaps every element of this cell's slice with the matching element of `other`
+    ///
+    /// `other` must not overlap the memory of this cell, which is checked in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let mut shared = [1, 2, 3];
+    /// let cell = SuperCell::from_mut_slice(&mut shared);
+    /// let mut staged = [4, 5, 6];
+    /// cell.swap_with_slice(&mut staged);
+    /// assert_eq!(staged, [1, 2, 3]);
+    /// assert_eq!(shared, [4, 5, 6]);
+    /// ```
+    pub fn swap_with_slice(&self, other : &mut [T]) {
+        let len = self.as_ptr().len();
+        assert!(len == other.len(), "destination and source slices have different lengths");
+        let this = self.as_ptr().cast::<T>();
+        debug_assert!(
+            this.wrapping_add(len) <= other.as_mut_ptr() || other.as_mut_ptr().wrapping_add(len) <= this || size_of::<T>() == 0,
+            "the slice to swap with overlaps the cell"
+        );
+        // SAFETY: Both pointers are valid for `len` elements and do not overlap.
+        unsafe { ptr::swap_nonoverlapping(this, other.as_mut_ptr(), len) }
+    }
+}
+
+/// In place sorting, delegating to the slice methods of the same name
+///
+/// Mutating elements through other cells while a sort runs, for example from inside the
+/// comparator, is an error. The sort holds a mutable reference to the whole slice until it
+/// returns, so under the [aliasing model](SuperCell#aliasing-model) this is undefined behaviour
+/// rather than just a scrambled order.
+impl<T> SuperCell<[T]> {
+    /// Sorts the slice, preserving the order of equal elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let mut scores = [30, 10, 20];
+    /// let cell = SuperCell::from_mut_slice(&mut scores);
+    /// cell.sort();
+    /// assert!(cell.is_sorted());
+    /// assert_eq!(scores, [10, 20, 30]);
+    /// ```
+    #[inline]
+    pub fn sort(&self) where T : Ord {
+        self.get_mut().sort()
+    }
+
+    /// Sorts the slice with a comparator, preserving the order of equal elements
+    #[inline]
+    pub fn sort_by<F : FnMut(&T, &T) -> Ordering>(&self, compare : F) {
+        self.get_mut().sort_by(compare)
+    }
+
+    /// Sorts the slice with a key extraction function, preserving the order of equal elements
+    #[inline]
+    pub fn sort_by_key<K : Ord, F : FnMut(&T) -> K>(&self, f : F) {
+        self.get_mut().sort_by_key(f)
+    }
+
+    /// Sorts the slice without preserving the order of equal elements
+    #[inline]
+    pub fn sort_unstable(&self) where T : Ord {
+        self.get_mut().sort_unstable()
+    }
+
+    /// Sorts the slice with a comparator, without preserving the order of equal elements
+    #[inline]
+    pub fn sort_unstable_by<F : FnMut(&T, &T) -> Ordering>(&self, compare : F) {
+        self.get_mut().sort_unstable_by(compare)
+    }
+
+    /// Sorts the slice with a key extraction function, without preserving the order of equal
+    /// elements
+    #[inline]
+    pub fn sort_unstable_by_key<K : Ord, F : FnMut(&T) -> K>(&self, f : F) {
+        self.get_mut().sort_unstable_by_key(f)
+    }
+
+    /// Returns `true` if the elements are sorted
+    #[inline]
+    pub fn is_sorted(&self) -> bool where T : PartialOrd {
+        self.get().is_sorted()
+    }
+
+    /// Returns `true` if `compare` holds for every pair of neighbouring elements
+    #[inline]
+    pub fn is_sorted_by<F : FnMut(&T, &T) -> bool>(&self, compare : F) -> bool {
+        self.get().is_sorted_by(compare)
+    }
+}
+
+impl<T, const N : usize> SuperCell<[T; N]> {
+    /// Returns the cell of the element at `index`, without bounds checking
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than `N`, which is checked in debug builds.
+    #[inline]
+    pub unsafe fn get_unchecked(&self, index : usize) -> &SuperCell<T> {
+        let slice : &SuperCell<[T]> = self;
+        // SAFETY: Same contract as the slice version.
+        unsafe { slice.get_unchecked(index) }
+    }
+
+    /// Returns the cell of the elements in `range`, without bounds checking
+    ///
+    /// # Safety
+    ///
+    /// `range.start` must not be greater than `range.end`, and `range.end` must not be greater
+    /// than `N`, which is checked in debug builds.
+    #[inline]
+    pub unsafe fn get_unchecked_range(&self, range : Range<usize>) -> &SuperCell<[T]> {
+        let slice : &SuperCell<[T]> = self;
+        // SAFETY: Same contract as the slice version.
+        unsafe { slice.get_unchecked_range(range) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values : &[&str]) -> Vec<String> {
+        values.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn swap_strings() {
+        let result = SuperCell::new(strings(&["a", "b", "c"]));
+        let mut staged = strings(&["x", "y", "z"]);
+        let slice : &SuperCell<[String]> = SuperCell::from_mut_slice(result.get_mut());
+        slice.swap_with_slice(&mut staged);
+        assert_eq!(staged, strings(&["a", "b", "c"]));
+        assert_eq!(*result.get(), strings(&["x", "y", "z"]));
+    }
+
+    #[test]
+    #[should_panic(expected = "different lengths")]
+    fn swap_mismatched_lengths() {
+        let mut array = [1, 2, 3];
+        SuperCell::from_mut_slice(&mut array).swap_with_slice(&mut [4, 5]);
+    }
+
+    /// Counts drops into the cell it points to
+    struct Counted<'a>(usize, &'a SuperCell<usize>);
+
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            *self.1.get_mut() += 1;
+        }
+    }
+
+    #[test]
+    fn take_and_replace_all() {
+        let drops = SuperCell::new(0);
+        let mut values : Vec<_> = (0..4).map(|index| Counted(index, &drops)).collect();
+        let cell = SuperCell::from_mut_slice(&mut values);
+        let old = cell.replace_all(|index| Counted(index + 10, &drops));
+        assert_eq!(*drops.get(), 0);
+        assert_eq!(old.iter().map(|value| value.0).collect::<Vec<_>>(), [0, 1, 2, 3]);
+        drop(old);
+        assert_eq!(*drops.get(), 4);
+        assert_eq!(cell.get().iter().map(|value| value.0).collect::<Vec<_>>(), [10, 11, 12, 13]);
+        drop(values);
+        assert_eq!(*drops.get(), 8);
+
+        let mut values = strings(&["a", "b"]);
+        assert_eq!(SuperCell::from_mut_slice(&mut values).take_all(), ["a", "b"]);
+        assert_eq!(values, ["", ""]);
+        let empty : &mut [String] = &mut [];
+        assert!(SuperCell::from_mut_slice(empty).take_all().is_empty());
+    }
+
+    #[test]
+    fn replace_all_panic_leaves_slice() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        let drops = SuperCell::new(0);
+        let mut values : Vec<_> = (0..4).map(|index| Counted(index, &drops)).collect();
+        let cell = SuperCell::from_mut_slice(&mut values);
+        let result = catch_unwind(AssertUnwindSafe(|| cell.replace_all(|index| {
+            assert!(index < 2, "replacement {index} failed");
+            Counted(index + 10, &drops)
+        })));
+        assert!(result.is_err());
+        // Only the two replacements built before the panic were dropped.
+        assert_eq!(*drops.get(), 2);
+        assert_eq!(cell.get().iter().map(|value| value.0).collect::<Vec<_>>(), [0, 1, 2, 3]);
+        drop(values);
+        assert_eq!(*drops.get(), 6);
+    }
+
+    #[test]
+    fn update_all_matches_cells() {
+        let mut bulk : Vec<u64> = (0..257).collect();
+        let mut single = bulk.clone();
+        SuperCell::from_mut_slice(&mut bulk).update_all(|index, value| *value = *value * 3 + index as u64);
+        for (index, cell) in SuperCell::from_mut_slice(&mut single).as_slice_of_cells().iter().enumerate() {
+            *cell.get_mut() = *cell.get() * 3 + index as u64;
+        }
+        assert_eq!(bulk, single);
+    }
+
+    #[test]
+    fn large_float_buffer() {
+        let mut cells : Vec<f32> = (0..100_000).map(|value| value as f32 * 0.5).collect();
+        let mut plain = cells.clone();
+        let cell = SuperCell::from_mut_slice(&mut cells);
+        cell.update_all(|index, value| *value = value.mul_add(1.5, index as f32));
+        cell.map_in_place(|value| value.sqrt());
+        for (index, value) in plain.iter_mut().enumerate() {
+            *value = value.mul_add(1.5, index as f32);
+            *value = value.sqrt();
+        }
+        assert_eq!(cells, plain);
+    }
+
+    #[test]
+    fn sort_orders() {
+        let mut duplicates = [3, 1, 2, 3, 1];
+        let mut sorted = [1, 2, 3, 4];
+        let mut reversed = [4, 3, 2, 1];
+        for values in [&mut duplicates[..], &mut sorted, &mut reversed] {
+            let mut expected = values.to_vec();
+            expected.sort();
+            let cell = SuperCell::from_mut_slice(values);
+            cell.sort_unstable();
+            assert!(cell.is_sorted());
+            assert_eq!(*cell.get(), *expected);
+            cell.sort_by(|a, b| b.cmp(a));
+            assert!(cell.is_sorted_by(|a, b| a >= b));
+            cell.sort();
+            assert_eq!(*cell.get(), *expected);
+        }
+        assert_eq!(duplicates, [1, 1, 2, 3, 3]);
+    }
+
+    #[test]
+    fn stable_sort_keeps_order() {
+        let mut entries = [(2, "a"), (1, "b"), (2, "c"), (1, "d"), (0, "e")];
+        let cell = SuperCell::from_mut_slice(&mut entries);
+        cell.sort_by_key(|&(score, _)| score);
+        assert_eq!(*cell.get(), [(0, "e"), (1, "b"), (1, "d"), (2, "a"), (2, "c")]);
+        cell.sort_unstable_by_key(|&(_, name)| core::cmp::Reverse(name));
+        assert!(cell.is_sorted_by(|a, b| a.1 >= b.1));
+        cell.sort_unstable_by(|a, b| a.1.cmp(b.1));
+        assert_eq!(entries.map(|(_, name)| name), ["a", "b", "c", "d", "e"]);
+    }
+
+    #[test]
+    fn unchecked_access() {
+        let array = SuperCell::new([0u32; 8]);
+        unsafe {
+            *array.get_unchecked(0).get_mut() = 1;
+            *array.get_unchecked(7).get_mut() = 2;
+            let middle = array.get_unchecked_range(2..5);
+            middle.update_all(|index, value| *value = 10 + index as u32);
+            let slice : &SuperCell<[u32]> = &array;
+            assert_eq!(*slice.get_unchecked(3).get(), 11);
+            assert!(slice.get_unchecked_range(8..8).get().is_empty());
+        }
+        assert_eq!(*array.get(), [1, 0, 10, 11, 12, 0, 0, 2]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "index out of bounds")]
+    fn unchecked_out_of_bounds() {
+        let array = SuperCell::new([0u32; 4]);
+        unsafe { array.get_unchecked(4) };
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "range out of bounds")]
+    fn unchecked_range_out_of_bounds() {
+        let array = SuperCell::new([0u32; 4]);
+        unsafe { array.get_unchecked_range(2..5) };
+    }
+}
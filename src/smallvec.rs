@@ -0,0 +1,255 @@
+use alloc::vec::Vec;
+use core::slice;
+use smallvec::{Array, SmallVec};
+use crate::SuperCell;
+
+impl<A : Array> SuperCell<SmallVec<A>> {
+    /// Returns a slice of cells over the elements of the `SmallVec` in this cell
+    ///
+    /// Correct whether the elements are inline or spilled to the heap. The view is invalidated
+    /// by anything that moves the elements, which includes spilling on a push past the inline
+    /// capacity and every method that removes elements. Take a new view afterwards.
+    ///
+    /// Inline elements are part of the `SmallVec` itself, so the pointer is taken through
+    /// `as_mut_ptr` to allow writing through the cells. That also ends any earlier view, so only
+    /// the latest one may be used.
+    #[inline]
+    pub fn as_slice_of_cells(&self) -> &[SuperCell<A::Item>] {
+        let list = self.get_mut();
+        // SAFETY: `SuperCell<T>` has the same memory layout as `T`, the pointer allows writes, and
+        // the elements live as long as the cell, whether they are stored inline or on the heap.
+        unsafe { slice::from_raw_parts(list.as_mut_ptr().cast::<SuperCell<A::Item>>(), list.len()) }
+    }
+
+    /// Appends every item of `iter`
+    pub fn extend_from_iter<I : IntoIterator<Item = A::Item>>(&self, iter : I) {
+        self.get_mut().extend(iter)
+    }
+
+    /// Returns `true` if the elements have been moved to the heap
+    #[inline]
+    pub fn spilled(&self) -> bool {
+        self.get().spilled()
+    }
+
+    /// Consumes the cell, returning the elements as a `Vec`, without copying if they have spilled
+    #[inline]
+    pub fn into_vec(self) -> Vec<A::Item> {
+        self.into_inner().into_vec()
+    }
+
+    /// Creates a cell from a `Vec`, reusing its allocation if it does not fit inline
+    #[inline]
+    pub fn from_vec(vec : Vec<A::Item>) -> Self {
+        SuperCell::new(SmallVec::from_vec(vec))
+    }
+}
+
+/// One shot `SmallVec` operations, none of which hand out a borrow of the list
+impl<A : Array> SuperCell<SmallVec<A>> {
+    /// Appends `value` to the back of the list, spilling to the heap if the inline storage is full
+    #[inline]
+    pub fn push(&self, value : A::Item) {
+        self.get_mut().push(value)
+    }
+
+    /// Removes the last element and returns it
+    #[inline]
+    pub fn pop(&self) -> Option<A::Item> {
+        self.get_mut().pop()
+    }
+
+    /// Removes every element, keeping the capacity
+    #[inline]
+    pub fn clear(&self) {
+        self.get_mut().clear()
+    }
+
+    /// Removes the element at `index` and returns it, moving the last element into its place
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn swap_remove(&self, index : usize) -> A::Item {
+        self.get_mut().swap_remove(index)
+    }
+
+    /// Inserts `value` at `index`, shifting every element after it to the right
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    #[inline]
+    pub fn insert(&self, index : usize, value : A::Item) {
+        self.get_mut().insert(index, value)
+    }
+
+    /// Removes the element at `index` and returns it, shifting every element after it to the left
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn remove(&self, index : usize) -> A::Item {
+        self.get_mut().remove(index)
+    }
+
+    /// Returns the number of elements in the list
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.get().len()
+    }
+
+    /// Returns `true` if the list has no elements
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.get().is_empty()
+    }
+
+    /// Reserves capacity for at least `additional` more elements
+    #[inline]
+    pub fn reserve(&self, additional : usize) {
+        self.get_mut().reserve(additional)
+    }
+
+    /// Shortens the list to `len` elements, dropping the rest
+    #[inline]
+    pub fn truncate(&self, len : usize) {
+        self.get_mut().truncate(len)
+    }
+
+    /// Removes every element and returns them, leaving an empty list
+    #[inline]
+    pub fn drain_all(&self) -> SmallVec<A> {
+        core::mem::take(self.get_mut())
+    }
+}
+
+impl<T> SuperCell<T> {
+    /// Wraps every element of a `SmallVec` in a cell, without copying if the elements have
+    /// spilled
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use smallvec::{smallvec, SmallVec};
+    /// use super_cell::SuperCell;
+    /// let values : SmallVec<[u32; 2]> = smallvec![1, 2, 3];
+    /// let cells = SuperCell::wrap_smallvec(values);
+    /// cells[2].set(30);
+    /// assert_eq!(*SuperCell::unwrap_smallvec(cells), [1, 2, 30]);
+    /// ```
+    pub fn wrap_smallvec<const N : usize>(vec : SmallVec<[T; N]>) -> SmallVec<[SuperCell<T>; N]>
+        where [T; N] : Array<Item = T>, [SuperCell<T>; N] : Array<Item = SuperCell<T>> {
+        if vec.spilled() {
+            SmallVec::from_vec(SuperCell::wrap_vec(vec.into_vec()))
+        } else {
+            vec.into_iter().map(SuperCell::new).collect()
+        }
+    }
+
+    /// Reverse of [`SuperCell::wrap_smallvec`]
+    pub fn unwrap_smallvec<const N : usize>(vec : SmallVec<[SuperCell<T>; N]>) -> SmallVec<[T; N]>
+        where [T; N] : Array<Item = T>, [SuperCell<T>; N] : Array<Item = SuperCell<T>> {
+        if vec.spilled() {
+            SmallVec::from_vec(SuperCell::unwrap_vec(vec.into_vec()))
+        } else {
+            vec.into_iter().map(SuperCell::into_inner).collect()
+        }
+    }
+}
+
+impl<A : Array> Extend<A::Item> for &SuperCell<SmallVec<A>> {
+    #[inline]
+    fn extend<I : IntoIterator<Item = A::Item>>(&mut self, iter : I) {
+        self.extend_from_iter(iter)
+    }
+}
+
+impl<'a, A : Array> IntoIterator for &'a SuperCell<SmallVec<A>> {
+    type Item = &'a SuperCell<A::Item>;
+    type IntoIter = slice::Iter<'a, SuperCell<A::Item>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice_of_cells().iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use smallvec::smallvec;
+    use super::*;
+
+    fn check(list : &SuperCell<SmallVec<[u32; 4]>>, expected : &[u32]) {
+        assert_eq!(list.len(), expected.len());
+        let cells = list.as_slice_of_cells();
+        assert_eq!(cells.len(), expected.len());
+        for (cell, value) in cells.iter().zip(expected) {
+            assert_eq!(cell.get(), value);
+        }
+    }
+
+    #[test]
+    fn across_spill_boundary() {
+        let list = SuperCell::new(SmallVec::<[u32; 4]>::new());
+        let mut expected = Vec::new();
+        for value in 0..10 {
+            list.push(value);
+            expected.push(value);
+            assert_eq!(list.spilled(), value >= 4);
+            check(&list, &expected);
+            for (cell, value) in list.into_iter().zip(&mut expected) {
+                *cell.get_mut() += 1;
+                *value += 1;
+            }
+            check(&list, &expected);
+        }
+        assert_eq!(list.pop(), expected.pop());
+        assert_eq!(list.remove(0), expected.remove(0));
+        assert_eq!(list.swap_remove(1), expected.swap_remove(1));
+        list.insert(2, 100);
+        expected.insert(2, 100);
+        check(&list, &expected);
+        list.truncate(3);
+        expected.truncate(3);
+        // Still spilled, truncating does not move the elements back inline.
+        assert!(list.spilled());
+        check(&list, &expected);
+        list.clear();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn conversions() {
+        let inline : SmallVec<[String; 2]> = smallvec![String::from("a")];
+        let cells = SuperCell::wrap_smallvec(inline);
+        assert!(!cells.spilled());
+        cells[0].get_mut().push('b');
+        assert_eq!(*SuperCell::unwrap_smallvec(cells), ["ab"]);
+
+        let spilled : SmallVec<[u64; 2]> = (0..5).collect();
+        let heap = spilled.as_ptr();
+        let cells = SuperCell::wrap_smallvec(spilled);
+        assert_eq!(cells.as_ptr().cast::<u64>(), heap);
+        let values = SuperCell::unwrap_smallvec(cells);
+        assert_eq!(values.as_ptr(), heap);
+
+        let list : SuperCell<SmallVec<[u64; 2]>> = SuperCell::from_vec(values.into_vec());
+        (&list).extend([5, 6]);
+        assert_eq!(list.drain_all().len(), 7);
+        list.extend_from_iter(0..2);
+        assert_eq!(list.into_vec(), [0, 1]);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_passthrough() {
+        let list : SuperCell<SmallVec<[u8; 2]>> = SuperCell::new(smallvec![1, 2, 3]);
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!(json, "[1,2,3]");
+        let list : SuperCell<SmallVec<[u8; 2]>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(*list.get().as_slice(), [1, 2, 3]);
+    }
+}
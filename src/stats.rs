@@ -0,0 +1,108 @@
+//! Access counters for cells wrapped with [`SuperCell::labeled`](crate::SuperCell::labeled)
+
+use std::collections::BTreeMap;
+use std::string::String;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::vec::Vec;
+
+/// Number of reads and writes counted under a label
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CellStats {
+    pub reads : u64,
+    pub writes : u64
+}
+
+pub(crate) struct Counters {
+    reads : AtomicU64,
+    writes : AtomicU64
+}
+
+impl Counters {
+    #[inline]
+    pub(crate) fn read(&self) {
+        self.reads.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn write(&self) {
+        self.writes.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Counters are leaked once per label, so wrappers can hold on to them without a lock
+static REGISTRY : Mutex<BTreeMap<String, &'static Counters>> = Mutex::new(BTreeMap::new());
+
+pub(crate) fn counters(label : &str) -> &'static Counters {
+    let mut registry = REGISTRY.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(counters) = registry.get(label) {
+        return counters;
+    }
+    let counters = Box::leak(Box::new(Counters { reads : AtomicU64::new(0), writes : AtomicU64::new(0) }));
+    registry.insert(String::from(label), counters);
+    counters
+}
+
+/// Returns the counters of every label used so far, sorted by label
+///
+/// # Examples
+///
+/// ```
+/// use super_cell::SuperCell;
+/// use super_cell::stats::{report, CellStats};
+/// let cell = SuperCell::new(0);
+/// let labeled = cell.labeled("example.counter");
+/// labeled.set(*labeled.get() + 1);
+/// let stats = report().into_iter().find(|(label, _)| label == "example.counter").map(|(_, stats)| stats);
+/// assert_eq!(stats, Some(CellStats { reads : 1, writes : 1 }));
+/// ```
+pub fn report() -> Vec<(String, CellStats)> {
+    let registry = REGISTRY.lock().unwrap_or_else(PoisonError::into_inner);
+    registry.iter().map(|(label, counters)| (label.clone(), CellStats {
+        reads : counters.reads.load(Ordering::Relaxed),
+        writes : counters.writes.load(Ordering::Relaxed)
+    })).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use crate::SuperCell;
+    use super::*;
+
+    fn stats(label : &str) -> Option<CellStats> {
+        report().into_iter().find(|(name, _)| name == label).map(|(_, stats)| stats)
+    }
+
+    #[test]
+    fn counts_per_label() {
+        let positions = SuperCell::new(vec![0u32; 4]);
+        let velocities = SuperCell::new(vec![1u32; 4]);
+        let positions = positions.labeled("stats.test.positions");
+        let velocities = velocities.labeled("stats.test.velocities");
+        for index in 0..4 {
+            positions.get_mut()[index] += velocities.get()[index];
+        }
+        positions.set(vec![]);
+        assert_eq!(stats("stats.test.positions"), Some(CellStats { reads : 0, writes : 5 }));
+        assert_eq!(stats("stats.test.velocities"), Some(CellStats { reads : 4, writes : 0 }));
+    }
+
+    #[test]
+    fn shared_label_across_threads() {
+        let cells : Vec<_> = (0..4).map(SuperCell::new).collect();
+        thread::scope(|scope| {
+            for cell in &cells {
+                scope.spawn(move || {
+                    let labeled = cell.labeled("stats.test.shared");
+                    for _ in 0..10 {
+                        labeled.get();
+                    }
+                });
+            }
+        });
+        assert_eq!(stats("stats.test.shared"), Some(CellStats { reads : 40, writes : 0 }));
+        let labels : Vec<_> = report().into_iter().map(|(label, _)| label).collect();
+        assert!(labels.is_sorted());
+    }
+}
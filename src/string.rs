@@ -0,0 +1,253 @@
+use alloc::string::String;
+use core::fmt;
+use crate::SuperCell;
+
+/// Adapter returned by [`SuperCell::writer`] implementing `fmt::Write` for a `SuperCell<String>`
+#[derive(Clone, Copy)]
+pub struct StringWriter<'a> {
+    cell : &'a SuperCell<String>
+}
+
+impl SuperCell<String> {
+    /// Returns an adapter implementing `fmt::Write` which appends to the string in this cell
+    ///
+    /// `fmt::Write` is also implemented for `&SuperCell<String>` directly, this is for places
+    /// where a named writer type reads better.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::fmt::Write;
+    /// use super_cell::SuperCell;
+    /// let log = SuperCell::new(String::new());
+    /// write!(log.writer(), "{}-{}", 1, 2).unwrap();
+    /// write!(&log, "-{}", 3).unwrap();
+    /// assert_eq!(log.get(), "1-2-3");
+    /// ```
+    #[inline]
+    pub fn writer(&self) -> StringWriter<'_> {
+        StringWriter { cell : self }
+    }
+
+    /// Clears the string, keeping its capacity
+    #[inline]
+    pub fn clear(&self) {
+        self.get_mut().clear()
+    }
+
+    /// Appends every string or character of `iter`
+    ///
+    /// Same as the `Extend` implementations for `&SuperCell<String>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let text = SuperCell::new(String::from("a"));
+    /// text.extend_from_iter(['b', 'c']);
+    /// text.extend_from_iter(["d", "e"]);
+    /// assert_eq!(text.get(), "abcde");
+    /// ```
+    #[inline]
+    pub fn extend_from_iter<I : IntoIterator>(&self, iter : I) where String : Extend<I::Item> {
+        self.get_mut().extend(iter)
+    }
+
+    /// Takes the string out of the cell, leaving an empty one in its place
+    #[inline]
+    pub fn take_string(&self) -> String {
+        core::mem::take(self.get_mut())
+    }
+
+    /// Appends `s` to the end of the string
+    #[inline]
+    pub fn push_str(&self, s : &str) {
+        self.get_mut().push_str(s)
+    }
+
+    /// Appends `c` to the end of the string
+    #[inline]
+    pub fn push(&self, c : char) {
+        self.get_mut().push(c)
+    }
+
+    /// Shortens the string to `len` bytes, see `String::truncate`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` does not lie on a `char` boundary.
+    #[inline]
+    pub fn truncate(&self, len : usize) {
+        self.get_mut().truncate(len)
+    }
+
+    /// Replaces the contents with `s`, reusing the existing allocation where it is large enough
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let status = SuperCell::new(String::from("starting"));
+    /// status.set_str("ready");
+    /// assert_eq!(status.get(), "ready");
+    /// ```
+    #[inline]
+    pub fn set_str(&self, s : &str) {
+        let text = self.get_mut();
+        text.clear();
+        text.push_str(s);
+    }
+
+    /// Returns the length of the string in bytes
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.get().len()
+    }
+
+    /// Returns `true` if the string is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.get().is_empty()
+    }
+}
+
+impl fmt::Write for &SuperCell<String> {
+    #[inline]
+    fn write_str(&mut self, s : &str) -> fmt::Result {
+        self.get_mut().write_str(s)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c : char) -> fmt::Result {
+        self.get_mut().write_char(c)
+    }
+
+    #[inline]
+    fn write_fmt(&mut self, args : fmt::Arguments<'_>) -> fmt::Result {
+        self.get_mut().write_fmt(args)
+    }
+}
+
+impl Extend<char> for &SuperCell<String> {
+    #[inline]
+    fn extend<I : IntoIterator<Item = char>>(&mut self, iter : I) {
+        let iter = iter.into_iter();
+        let text = self.get_mut();
+        text.reserve(iter.size_hint().0);
+        text.extend(iter);
+    }
+}
+
+impl<'a> Extend<&'a str> for &SuperCell<String> {
+    #[inline]
+    fn extend<I : IntoIterator<Item = &'a str>>(&mut self, iter : I) {
+        self.get_mut().extend(iter)
+    }
+}
+
+impl fmt::Write for StringWriter<'_> {
+    #[inline]
+    fn write_str(&mut self, s : &str) -> fmt::Result {
+        self.cell.get_mut().write_str(s)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c : char) -> fmt::Result {
+        self.cell.get_mut().write_char(c)
+    }
+
+    #[inline]
+    fn write_fmt(&mut self, args : fmt::Arguments<'_>) -> fmt::Result {
+        self.cell.get_mut().write_fmt(args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use super::*;
+
+    #[test]
+    fn interleaved_writes() {
+        let log = SuperCell::new(String::new());
+        let mut writer = log.writer();
+        write!(writer, "a={}", 1).unwrap();
+        assert_eq!(log.get(), "a=1");
+        writeln!(&log, ", b={}", 2).unwrap();
+        assert_eq!(log.get(), "a=1, b=2\n");
+        writer.write_char('c').unwrap();
+        assert_eq!(log.take_string(), "a=1, b=2\nc");
+        assert_eq!(log.get(), "");
+    }
+
+    #[test]
+    fn clear_keeps_capacity() {
+        let log = SuperCell::new(String::new());
+        write!(&log, "{}", "x".repeat(100)).unwrap();
+        let capacity = log.get().capacity();
+        let pointer = log.get().as_ptr();
+        log.clear();
+        assert!(log.get().is_empty());
+        write!(&log, "{}", "y".repeat(50)).unwrap();
+        assert_eq!(log.get().capacity(), capacity);
+        assert_eq!(log.get().as_ptr(), pointer);
+    }
+
+    #[test]
+    fn set_str_reuses_capacity() {
+        let status = SuperCell::new(String::with_capacity(32));
+        let pointer = status.get().as_ptr();
+        for value in ["starting", "ready", "shutting down"] {
+            status.set_str(value);
+            assert_eq!(status.get(), value);
+            assert_eq!(status.len(), value.len());
+        }
+        assert_eq!(status.get().capacity(), 32);
+        assert_eq!(status.get().as_ptr(), pointer);
+    }
+
+    #[test]
+    fn truncate_panics_like_string() {
+        let cell = SuperCell::new(String::from("añb"));
+        let mut plain = String::from("añb");
+        let expected = catch_unwind(AssertUnwindSafe(|| plain.truncate(2))).expect_err("Should panic");
+        let actual = catch_unwind(AssertUnwindSafe(|| cell.truncate(2))).expect_err("Should panic");
+        let message = |payload : &Box<dyn std::any::Any + Send>| payload.downcast_ref::<&str>().map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned());
+        assert!(message(&expected).is_some());
+        assert_eq!(message(&actual), message(&expected));
+        assert_eq!(cell.get(), "añb");
+        cell.truncate(3);
+        assert_eq!(cell.get(), "añ");
+    }
+
+    #[test]
+    fn interleaved_appends() {
+        fn log_start(log : &SuperCell<String>, name : &str) {
+            log.push_str(name);
+            log.push('(');
+        }
+        fn log_end(log : &SuperCell<String>) {
+            log.push(')');
+        }
+        let log = SuperCell::new(String::new());
+        assert!(log.is_empty());
+        log_start(&log, "a");
+        log_start(&log, "b");
+        log_end(&log);
+        log_end(&log);
+        assert_eq!(log.get(), "a(b())");
+        assert!(!log.is_empty());
+    }
+
+    #[test]
+    fn extend_in_order() {
+        let text = SuperCell::new(String::new());
+        let mut shared = &text;
+        shared.extend("abc".chars().chain("def".chars()));
+        shared.extend(["g", "h"]);
+        text.extend_from_iter(('i'..='k').rev());
+        assert_eq!(text.get(), "abcdefghkji");
+    }
+}
@@ -0,0 +1,195 @@
+use core::any::TypeId;
+use core::cell::UnsafeCell;
+use core::marker::PhantomData;
+use std::collections::BTreeSet;
+use std::sync::{Mutex, PoisonError};
+use crate::SuperCell;
+
+/// Marker types with a live token
+static TOKENS : Mutex<BTreeSet<TypeId>> = Mutex::new(BTreeSet::new());
+
+/// Token proving access to every [`TokenCell`] marked with `Q`
+///
+/// At most one token per marker type exists at a time, which is checked when it is created.
+/// Like [`CellOwner`](crate::CellOwner), shared access to the token grants shared access to the
+/// cells and exclusive access grants exclusive access, but the token is a plain value that can
+/// be stored, passed to functions and sent to other threads.
+pub struct Token<Q : 'static> {
+    _marker : PhantomData<fn() -> Q>
+}
+
+/// Cell with the same sharing model as `SuperCell`, with accesses checked at compile time
+/// through a [`Token`]
+///
+/// # Examples
+///
+/// ```
+/// use super_cell::{Token, TokenCell};
+/// struct Physics;
+/// let mut token = Token::<Physics>::new();
+/// let cells = [TokenCell::<Physics, _>::new(1), TokenCell::new(2)];
+/// *cells[0].get_mut(&mut token) += 10;
+/// assert_eq!(*cells[0].get(&token), 11);
+/// ```
+///
+/// Two mutable references cannot be live at once without [`Token::get_mut2`]:
+///
+/// ```compile_fail
+/// use super_cell::{Token, TokenCell};
+/// struct Physics;
+/// let mut token = Token::<Physics>::new();
+/// let (a, b) = (TokenCell::<Physics, _>::new(1), TokenCell::new(2));
+/// let a = a.get_mut(&mut token);
+/// let b = b.get_mut(&mut token);
+/// *a += *b;
+/// ```
+#[repr(transparent)]
+pub struct TokenCell<Q : 'static, T : ?Sized> {
+    _marker : PhantomData<fn() -> Q>,
+    value : UnsafeCell<T>
+}
+
+// SAFETY: Shared access to the value needs a `&Token<Q>`, and exclusive access a
+// `&mut Token<Q>`, which can be on any thread.
+unsafe impl<Q, T : ?Sized + Send + Sync> Sync for TokenCell<Q, T> {}
+
+impl<Q : 'static> Token<Q> {
+    /// Creates the token for `Q`
+    ///
+    /// # Panics
+    ///
+    /// Panics if a token for `Q` already exists.
+    #[track_caller]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        match Self::try_new() {
+            Some(token) => token,
+            None => panic!("a token for `{}` already exists", core::any::type_name::<Q>())
+        }
+    }
+
+    /// Creates the token for `Q`, or returns `None` if one already exists
+    pub fn try_new() -> Option<Self> {
+        let mut tokens = TOKENS.lock().unwrap_or_else(PoisonError::into_inner);
+        tokens.insert(TypeId::of::<Q>()).then(|| Self { _marker : PhantomData })
+    }
+
+    /// Returns mutable references to two cells at once
+    ///
+    /// # Panics
+    ///
+    /// Panics if the values of the cells overlap.
+    #[track_caller]
+    pub fn get_mut2<'a, T, U>(&'a mut self, first : &'a TokenCell<Q, T>, second : &'a TokenCell<Q, U>) -> (&'a mut T, &'a mut U) {
+        let (first, second) = (first.value.get(), second.value.get());
+        let (start, other) = (first as usize, second as usize);
+        let overlap = start < other + size_of::<U>() && other < start + size_of::<T>();
+        assert!(!overlap, "the cells overlap");
+        // SAFETY: The token is borrowed mutably for `'a`, and the values are disjoint.
+        unsafe { (&mut *first, &mut *second) }
+    }
+}
+
+impl<Q : 'static> Drop for Token<Q> {
+    fn drop(&mut self) {
+        TOKENS.lock().unwrap_or_else(PoisonError::into_inner).remove(&TypeId::of::<Q>());
+    }
+}
+
+impl<Q : 'static, T> TokenCell<Q, T> {
+    #[inline]
+    pub const fn new(value : T) -> Self {
+        Self {
+            _marker : PhantomData,
+            value : UnsafeCell::new(value)
+        }
+    }
+
+    /// Converts a `SuperCell` into a token cell holding the same value
+    #[inline]
+    pub fn from_cell(cell : SuperCell<T>) -> Self {
+        Self::new(cell.into_inner())
+    }
+
+    /// Converts the token cell back into a `SuperCell`
+    #[inline]
+    pub fn into_cell(self) -> SuperCell<T> {
+        SuperCell::new(self.into_inner())
+    }
+
+    /// Consumes the cell, returning the wrapped value
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<Q : 'static, T : ?Sized> TokenCell<Q, T> {
+    /// Returns a shared reference to the value, which lives as long as the token borrow
+    #[inline]
+    pub fn get<'a>(&'a self, _token : &'a Token<Q>) -> &'a T {
+        // SAFETY: Mutable references need the token borrowed mutably, which it is not for `'a`,
+        // and no other token for `Q` exists.
+        unsafe { &*self.value.get() }
+    }
+
+    /// Returns a mutable reference to the value, which keeps the token borrowed mutably
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub fn get_mut<'a>(&'a self, _token : &'a mut Token<Q>) -> &'a mut T {
+        // SAFETY: The only token for `Q` is borrowed mutably for `'a`, so no other reference to a
+        // cell marked with `Q` can be created in the meantime.
+        unsafe { &mut *self.value.get() }
+    }
+
+    /// Returns a raw pointer to the underlying data in this cell
+    #[inline]
+    pub const fn as_ptr(&self) -> *mut T {
+        self.value.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "already exists")]
+    fn duplicate_token() {
+        struct Duplicate;
+        let _token = Token::<Duplicate>::new();
+        Token::<Duplicate>::new();
+    }
+
+    #[test]
+    fn token_across_threads() {
+        struct Shared;
+        let cells : Vec<TokenCell<Shared, u32>> = (0..4).map(TokenCell::new).collect();
+        let mut token = Token::new();
+        let mut token = thread::scope(|scope| {
+            scope.spawn(|| {
+                for cell in &cells {
+                    *cell.get_mut(&mut token) *= 2;
+                }
+                token
+            }).join().unwrap()
+        });
+        let (first, last) = token.get_mut2(&cells[0], &cells[3]);
+        *first += *last;
+        let values : Vec<_> = cells.iter().map(|cell| *cell.get(&token)).collect();
+        assert_eq!(values, [6, 2, 4, 6]);
+    }
+
+    #[test]
+    fn drop_allows_new_token() {
+        struct Reused;
+        let cell = TokenCell::<Reused, _>::from_cell(SuperCell::new(1));
+        let token = Token::<Reused>::new();
+        assert!(Token::<Reused>::try_new().is_none());
+        drop(token);
+        let mut token = Token::<Reused>::new();
+        *cell.get_mut(&mut token) += 1;
+        assert_eq!(cell.into_cell().into_inner(), 2);
+    }
+}
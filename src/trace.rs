@@ -0,0 +1,167 @@
+use core::fmt::Debug;
+use core::panic::Location;
+use std::collections::BTreeMap;
+use std::string::String;
+use std::sync::{Mutex, PoisonError};
+use crate::SuperCell;
+
+type FormatFn = unsafe fn(*const ()) -> String;
+
+/// Formats the value behind a type erased pointer, registered by [`SuperCell::trace_values`]
+struct Formatter {
+    type_name : &'static str,
+    format : FormatFn
+}
+
+/// Cells with value tracing turned on, keyed by address
+///
+/// The type name is checked on every lookup so a cell of another type placed at an address
+/// that was left registered is never formatted as the old type.
+static FORMATTERS : Mutex<BTreeMap<usize, Formatter>> = Mutex::new(BTreeMap::new());
+
+/// # Safety
+///
+/// `ptr` must point to a live `T`.
+unsafe fn format_value<T : Debug>(ptr : *const ()) -> String {
+    // SAFETY: Guaranteed by the caller.
+    format!("{:?}", unsafe { &*ptr.cast::<T>() })
+}
+
+impl<T : Debug> SuperCell<T> {
+    /// Turns logging of the old and new values on or off for writes to this cell
+    ///
+    /// Off by default, as values can be large. The setting is tied to the address of the cell,
+    /// so it should be turned off again before the cell is dropped or moved.
+    pub fn trace_values(&self, enabled : bool) {
+        let Some(key) = self.tracking_key() else {
+            return;
+        };
+        let mut formatters = FORMATTERS.lock().unwrap_or_else(PoisonError::into_inner);
+        if enabled {
+            formatters.insert(key, Formatter { type_name : core::any::type_name::<T>(), format : format_value::<T> });
+        } else {
+            formatters.remove(&key);
+        }
+    }
+}
+
+/// Guard logging a write to a cell when dropped, created before the write happens
+pub(crate) struct Write<'a, T : ?Sized> {
+    cell : &'a SuperCell<T>,
+    operation : &'static str,
+    location : &'static Location<'static>,
+    old : Option<(String, FormatFn)>
+}
+
+impl<'a, T : ?Sized> Write<'a, T> {
+    #[inline]
+    #[track_caller]
+    pub(crate) fn begin(cell : &'a SuperCell<T>, operation : &'static str) -> Self {
+        let mut old = None;
+        if log::log_enabled!(target : "super_cell", log::Level::Trace) {
+            if let Some(format) = Self::formatter(cell) {
+                // SAFETY: The formatter was registered for a `T` at this address.
+                old = Some((unsafe { format(cell.as_ptr() as *const ()) }, format));
+            }
+        }
+        Self { cell, operation, location : Location::caller(), old }
+    }
+
+    fn formatter(cell : &SuperCell<T>) -> Option<FormatFn> {
+        let key = cell.tracking_key()?;
+        let formatters = FORMATTERS.lock().unwrap_or_else(PoisonError::into_inner);
+        formatters.get(&key)
+            .filter(|formatter| formatter.type_name == core::any::type_name::<T>())
+            .map(|formatter| formatter.format)
+    }
+}
+
+impl<T : ?Sized> Drop for Write<'_, T> {
+    fn drop(&mut self) {
+        let name = core::any::type_name::<T>();
+        let address = self.cell.as_ptr() as *const ();
+        match self.old.take() {
+            Some((old, format)) => {
+                // SAFETY: Same formatter and address as in `begin`.
+                let new = unsafe { format(address) };
+                log::trace!(target : "super_cell", "{} {name}@{address:p} at {}: {old} -> {new}", self.operation, self.location);
+            }
+            None => log::trace!(target : "super_cell", "{} {name}@{address:p} at {}", self.operation, self.location)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Once;
+    use std::vec::Vec;
+    use super::*;
+
+    static MESSAGES : Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    struct Capture;
+
+    impl log::Log for Capture {
+        fn enabled(&self, metadata : &log::Metadata<'_>) -> bool {
+            metadata.target() == "super_cell"
+        }
+
+        fn log(&self, record : &log::Record<'_>) {
+            if self.enabled(record.metadata()) {
+                MESSAGES.lock().unwrap_or_else(PoisonError::into_inner).push(record.args().to_string());
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    /// Installs the logger once and returns the captured messages mentioning `marker`
+    fn captured(marker : &str) -> Vec<String> {
+        static INSTALL : Once = Once::new();
+        INSTALL.call_once(|| {
+            log::set_logger(&Capture).expect("No other logger should be installed");
+            log::set_max_level(log::LevelFilter::Trace);
+        });
+        MESSAGES.lock().unwrap_or_else(PoisonError::into_inner).iter().filter(|message| message.contains(marker)).cloned().collect()
+    }
+
+    #[derive(Debug, Default)]
+    struct Plain(u32);
+
+    #[derive(Debug, Default)]
+    struct Valued(u32);
+
+    #[test]
+    fn logs_writes() {
+        captured("");
+        let cell = SuperCell::new(Plain(1));
+        let line = line!() + 1;
+        cell.set(Plain(2));
+        cell.replace(Plain(3));
+        cell.with_mut(|value| value.0 += 1);
+        let messages = captured("Plain@");
+        assert_eq!(messages.len(), 3);
+        assert!(messages[0].starts_with("set super_cell::trace::tests::Plain@"));
+        assert!(messages[0].ends_with(&format!("at {}:{line}:14", file!())));
+        assert!(messages[1].starts_with("replace "));
+        assert!(messages[2].starts_with("with_mut "));
+        assert!(!messages.iter().any(|message| message.contains("->")));
+    }
+
+    #[test]
+    fn logs_values_when_enabled() {
+        captured("");
+        let cell = SuperCell::new(Valued(1));
+        cell.trace_values(true);
+        cell.set(Valued(2));
+        cell.take();
+        cell.trace_values(false);
+        cell.set(Valued(5));
+        let messages = captured("Valued@");
+        assert_eq!(messages.len(), 3);
+        assert!(messages[0].ends_with(": Valued(1) -> Valued(2)"));
+        assert!(messages[1].ends_with(": Valued(2) -> Valued(0)"));
+        assert!(!messages[2].contains("->"));
+        assert_eq!(cell.into_inner().0, 5);
+    }
+}
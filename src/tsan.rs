@@ -0,0 +1,89 @@
+use crate::SuperCell;
+
+#[cfg(feature = "tsan")]
+extern "C" {
+    fn __tsan_acquire(addr : *mut core::ffi::c_void);
+    fn __tsan_release(addr : *mut core::ffi::c_void);
+}
+
+/// ThreadSanitizer annotations
+///
+/// TSan only sees happens-before edges through synchronization it instruments, which does not
+/// include the standard library unless it is rebuilt with the sanitizer, nor custom atomics
+/// protocols, fences, or synchronization done by C code. Handing a value over through a cell in
+/// those cases is reported as a race even when it is properly synchronized.
+///
+/// The annotations model a single pattern: the thread handing the value over calls
+/// [`SuperCell::release_fence_for_tsan`] after its last access and before signalling, and the
+/// thread receiving it calls [`SuperCell::acquire_fence_for_tsan`] after being signalled and
+/// before its first access. TSan then treats everything before the release as happening before
+/// everything after the acquire, for that cell's address only. They do not synchronize anything
+/// themselves, so using them without real external synchronization hides genuine races.
+///
+/// Without the `tsan` feature both methods compile to nothing. With it, the test binary has to
+/// be built with `-Zsanitizer=thread` so that the TSan runtime is linked in.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::mpsc;
+/// use std::thread;
+/// use super_cell::SuperCell;
+/// let cell = SuperCell::new(0);
+/// let (sender, receiver) = mpsc::channel();
+/// thread::scope(|scope| {
+///     scope.spawn(|| {
+///         cell.set(1);
+///         cell.release_fence_for_tsan();
+///         sender.send(()).unwrap();
+///     });
+///     receiver.recv().unwrap();
+///     cell.acquire_fence_for_tsan();
+///     assert_eq!(*cell.get(), 1);
+/// });
+/// ```
+impl<T : ?Sized> SuperCell<T> {
+    /// Tells TSan that this thread now synchronizes with the last release on this cell
+    #[inline(always)]
+    pub fn acquire_fence_for_tsan(&self) {
+        // SAFETY: The annotation only records the address, it is never dereferenced.
+        #[cfg(feature = "tsan")]
+        unsafe { __tsan_acquire(self.as_ptr().cast()) }
+    }
+
+    /// Tells TSan that accesses so far happen before the next acquire on this cell
+    #[inline(always)]
+    pub fn release_fence_for_tsan(&self) {
+        // SAFETY: The annotation only records the address, it is never dereferenced.
+        #[cfg(feature = "tsan")]
+        unsafe { __tsan_release(self.as_ptr().cast()) }
+    }
+}
+
+#[cfg(all(test, feature = "tsan"))]
+mod tests {
+    use std::sync::atomic::{fence, AtomicBool, Ordering};
+    use std::thread;
+    use super::*;
+
+    #[test]
+    fn fenced_handoff() {
+        // TSan does not model standalone fences, so the annotations provide the edge here.
+        let cell = SuperCell::new(vec![]);
+        let ready = AtomicBool::new(false);
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                cell.get_mut().extend([1, 2, 3]);
+                cell.release_fence_for_tsan();
+                fence(Ordering::Release);
+                ready.store(true, Ordering::Relaxed);
+            });
+            while !ready.load(Ordering::Relaxed) {
+                thread::yield_now();
+            }
+            fence(Ordering::Acquire);
+            cell.acquire_fence_for_tsan();
+            assert_eq!(*cell.get(), vec![1, 2, 3]);
+        });
+    }
+}
@@ -0,0 +1,55 @@
+use crate::SuperCell;
+
+macro_rules! impl_tuple_of_cells {
+    ($($name:ident : $index:tt),+) => {
+        impl<$($name),+> SuperCell<($($name,)+)> {
+            /// Returns a tuple of cells, one for each element of the tuple in this cell.
+            ///
+            /// Each element is projected from its actual place, so no layout is assumed.
+            pub fn as_tuple_of_cells(&self) -> ($(&SuperCell<$name>,)+) {
+                ($(crate::project!(self => $index),)+)
+            }
+        }
+    };
+}
+
+impl_tuple_of_cells!(A : 0);
+impl_tuple_of_cells!(A : 0, B : 1);
+impl_tuple_of_cells!(A : 0, B : 1, C : 2);
+impl_tuple_of_cells!(A : 0, B : 1, C : 2, D : 3);
+impl_tuple_of_cells!(A : 0, B : 1, C : 2, D : 3, E : 4);
+impl_tuple_of_cells!(A : 0, B : 1, C : 2, D : 3, E : 4, F : 5);
+impl_tuple_of_cells!(A : 0, B : 1, C : 2, D : 3, E : 4, F : 5, G : 6);
+impl_tuple_of_cells!(A : 0, B : 1, C : 2, D : 3, E : 4, F : 5, G : 6, H : 7);
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use super::*;
+
+    #[test]
+    fn pair_from_threads() {
+        let result = SuperCell::new((vec![0u8; 4], 0.0f64));
+        let (positions, velocity) = result.as_tuple_of_cells();
+        thread::scope(|scope| {
+            scope.spawn(|| positions.get_mut().iter_mut().for_each(|value| *value += 1));
+            scope.spawn(|| *velocity.get_mut() = 2.5);
+        });
+        assert_eq!(*result.get(), (vec![1u8; 4], 2.5));
+    }
+
+    #[test]
+    fn mixed_sizes() {
+        let result = SuperCell::new((1u8, 2u64, 3u16, String::from("4"), 5u32, 6i8, 7usize, 'h'));
+        let (a, b, c, d, e, f, g, h) = result.as_tuple_of_cells();
+        *a.get_mut() += 10;
+        *b.get_mut() += 10;
+        *c.get_mut() += 10;
+        d.get_mut().push('0');
+        *e.get_mut() += 10;
+        *f.get_mut() += 10;
+        *g.get_mut() += 10;
+        *h.get_mut() = 'i';
+        assert_eq!(*result.get(), (11, 12, 13, String::from("40"), 15, 16, 17, 'i'));
+    }
+}
@@ -0,0 +1,361 @@
+use alloc::vec::{self, Vec};
+use core::ops::RangeBounds;
+use core::slice;
+use crate::SuperCell;
+
+/// Extension method for collecting an iterator straight into a `Vec` of cells
+pub trait CollectCells : Iterator + Sized {
+    /// Collects the items into a `Vec<SuperCell<Item>>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::prelude::*;
+    /// let cells = (1..4).collect_cells();
+    /// *cells[0].get_mut() = 10;
+    /// assert_eq!(SuperCell::unwrap_vec(cells), vec![10, 2, 3]);
+    /// ```
+    fn collect_cells(self) -> Vec<SuperCell<Self::Item>> {
+        SuperCell::wrap_vec(self.collect())
+    }
+}
+
+impl<I : Iterator> CollectCells for I {}
+
+impl<T> SuperCell<Vec<T>> {
+    /// Returns a slice of cells over the elements of the `Vec` in this cell
+    ///
+    /// The view is invalidated by anything that reallocates or shrinks the `Vec`, including
+    /// [`SuperCell::push`], [`SuperCell::insert`] and [`SuperCell::reserve`] growing it and
+    /// every method that removes elements. Take a new view afterwards instead of reusing one.
+    #[inline]
+    pub fn as_slice_of_cells(&self) -> &[SuperCell<T>] {
+        let list = self.get();
+        // SAFETY: `SuperCell<T>` has the same memory layout as `T`, so writes through the cells go
+        // through an `UnsafeCell` as `Vec::as_ptr` requires, and the elements live as long as the cell.
+        unsafe { slice::from_raw_parts(list.as_ptr().cast::<SuperCell<T>>(), list.len()) }
+    }
+
+    /// Appends every item of `iter`, reserving space for the lower bound of its size hint first
+    ///
+    /// Same as the `Extend` implementation for `&SuperCell<Vec<T>>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let list = SuperCell::new(vec![1]);
+    /// list.extend_from_iter(2..4);
+    /// (&list).extend([4, 5]);
+    /// assert_eq!(*list.get(), vec![1, 2, 3, 4, 5]);
+    /// ```
+    pub fn extend_from_iter<I : IntoIterator<Item = T>>(&self, iter : I) {
+        let iter = iter.into_iter();
+        let list = self.get_mut();
+        list.reserve(iter.size_hint().0);
+        list.extend(iter);
+    }
+}
+
+/// One shot `Vec` operations, none of which hand out a borrow of the list
+impl<T> SuperCell<Vec<T>> {
+    /// Appends `value` to the back of the list
+    #[inline]
+    pub fn push(&self, value : T) {
+        self.get_mut().push(value)
+    }
+
+    /// Removes the last element and returns it
+    #[inline]
+    pub fn pop(&self) -> Option<T> {
+        self.get_mut().pop()
+    }
+
+    /// Removes every element, keeping the capacity
+    #[inline]
+    pub fn clear(&self) {
+        self.get_mut().clear()
+    }
+
+    /// Removes the element at `index` and returns it, moving the last element into its place
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn swap_remove(&self, index : usize) -> T {
+        self.get_mut().swap_remove(index)
+    }
+
+    /// Inserts `value` at `index`, shifting every element after it to the right
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    #[inline]
+    pub fn insert(&self, index : usize, value : T) {
+        self.get_mut().insert(index, value)
+    }
+
+    /// Removes the element at `index` and returns it, shifting every element after it to the left
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn remove(&self, index : usize) -> T {
+        self.get_mut().remove(index)
+    }
+
+    /// Returns the number of elements in the list
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.get().len()
+    }
+
+    /// Returns `true` if the list has no elements
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.get().is_empty()
+    }
+
+    /// Reserves capacity for at least `additional` more elements
+    #[inline]
+    pub fn reserve(&self, additional : usize) {
+        self.get_mut().reserve(additional)
+    }
+
+    /// Shortens the list to `len` elements, dropping the rest
+    #[inline]
+    pub fn truncate(&self, len : usize) {
+        self.get_mut().truncate(len)
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, in order
+    ///
+    /// `f` runs while the list is mutably borrowed, so it must not access this cell.
+    #[inline]
+    pub fn retain(&self, f : impl FnMut(&T) -> bool) {
+        self.get_mut().retain(f)
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, letting `f` modify them
+    ///
+    /// `f` runs while the list is mutably borrowed, so it must not access this cell.
+    #[inline]
+    pub fn retain_mut(&self, f : impl FnMut(&mut T) -> bool) {
+        self.get_mut().retain_mut(f)
+    }
+
+    /// Removes the elements in `range` and returns them as an owning iterator
+    ///
+    /// The elements are moved out before returning, so unlike `Vec::drain` the iterator does
+    /// not borrow the cell and the list can be used while it is alive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, like `Vec::drain`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use super_cell::SuperCell;
+    /// let jobs = SuperCell::new(vec![1, 2, 3, 4]);
+    /// for job in jobs.drain(..2) {
+    ///     jobs.push(job * 10);
+    /// }
+    /// assert_eq!(*jobs.get(), vec![3, 4, 10, 20]);
+    /// ```
+    pub fn drain<R : RangeBounds<usize>>(&self, range : R) -> vec::IntoIter<T> {
+        self.get_mut().drain(range).collect::<Vec<_>>().into_iter()
+    }
+
+    /// Removes every element and returns them along with the allocation, leaving an empty list
+    #[inline]
+    pub fn drain_all(&self) -> Vec<T> {
+        core::mem::take(self.get_mut())
+    }
+
+    /// Appends clones of every element of `other`
+    ///
+    /// `other` must not point into this cell, since the list may reallocate while it is read.
+    #[inline]
+    pub fn extend_from_slice(&self, other : &[T]) where T : Clone {
+        self.get_mut().extend_from_slice(other)
+    }
+
+    /// Removes consecutive repeated elements
+    #[inline]
+    pub fn dedup(&self) where T : PartialEq {
+        self.get_mut().dedup()
+    }
+}
+
+impl<T> Extend<T> for &SuperCell<Vec<T>> {
+    #[inline]
+    fn extend<I : IntoIterator<Item = T>>(&mut self, iter : I) {
+        self.extend_from_iter(iter)
+    }
+}
+
+impl<T> FromIterator<T> for SuperCell<Vec<T>> {
+    #[inline]
+    fn from_iter<I : IntoIterator<Item = T>>(iter : I) -> Self {
+        SuperCell::new(iter.into_iter().collect())
+    }
+}
+
+impl<T> IntoIterator for SuperCell<Vec<T>> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_inner().into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SuperCell<Vec<T>> {
+    type Item = &'a SuperCell<T>;
+    type IntoIter = slice::Iter<'a, SuperCell<T>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice_of_cells().iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collect_and_iterate() {
+        let list : SuperCell<Vec<u64>> = (1..=10).collect();
+        for cell in &list {
+            *cell.get_mut() *= 2;
+        }
+        assert_eq!(list.get()[9], 20);
+        let cells = list.get().iter().copied().collect_cells();
+        assert_eq!(cells.len(), 10);
+        assert_eq!(list.into_iter().sum::<u64>(), 110);
+    }
+
+    #[test]
+    fn extend_in_order() {
+        let list = SuperCell::new(Vec::new());
+        let mut shared = &list;
+        shared.extend(0..3);
+        list.extend_from_iter((3..5).chain([5, 6]));
+        assert_eq!(*list.get(), vec![0, 1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn one_shot_operations() {
+        let events = SuperCell::new(Vec::new());
+        assert!(events.is_empty());
+        for event in ["a", "b", "c", "d"] {
+            events.push(event);
+        }
+        events.insert(1, "e");
+        assert_eq!(*events.get(), ["a", "e", "b", "c", "d"]);
+        assert_eq!(events.remove(2), "b");
+        assert_eq!(events.swap_remove(0), "a");
+        assert_eq!(*events.get(), ["d", "e", "c"]);
+        assert_eq!(events.pop(), Some("c"));
+        events.truncate(1);
+        assert_eq!(events.len(), 1);
+        events.clear();
+        assert_eq!(events.pop(), None);
+    }
+
+    #[test]
+    fn growth_across_reallocation() {
+        let list = SuperCell::new(Vec::with_capacity(1));
+        list.push(0);
+        let capacity = list.get().capacity();
+        for value in 1..100 {
+            list.push(value);
+        }
+        assert!(list.get().capacity() > capacity);
+        assert_eq!(*list.get(), (0..100).collect::<Vec<_>>());
+        let cells = list.as_slice_of_cells();
+        cells[99].set(1000);
+        list.reserve(1000);
+        // `cells` is invalid after the reserve reallocated, take a new view.
+        assert_eq!(list.as_slice_of_cells()[99].get(), &1000);
+    }
+
+    #[test]
+    fn retain_drops_removed() {
+        struct Probe<'a>(u32, &'a SuperCell<Vec<u32>>);
+        impl Drop for Probe<'_> {
+            fn drop(&mut self) {
+                self.1.push(self.0);
+            }
+        }
+        let dropped = SuperCell::new(Vec::new());
+        let list = SuperCell::new((0..6).map(|value| Probe(value, &dropped)).collect::<Vec<_>>());
+        list.retain(|probe| probe.0 % 2 == 0);
+        assert_eq!(*dropped.get(), [1, 3, 5]);
+        list.retain_mut(|probe| {
+            probe.0 += 10;
+            probe.0 != 12
+        });
+        assert_eq!(*dropped.get(), [1, 3, 5, 12]);
+        assert_eq!(list.get().iter().map(|probe| probe.0).collect::<Vec<_>>(), [10, 14]);
+    }
+
+    #[test]
+    fn drain_empties() {
+        let list = SuperCell::new(vec![1, 2, 3, 4, 5]);
+        let middle = list.drain(1..3);
+        assert_eq!(*list.get(), [1, 4, 5]);
+        assert_eq!(middle.collect::<Vec<_>>(), [2, 3]);
+        assert_eq!(list.drain_all(), [1, 4, 5]);
+        assert!(list.is_empty());
+        assert_eq!(list.drain(..).len(), 0);
+    }
+
+    #[test]
+    fn extend_and_dedup() {
+        let list = SuperCell::new(vec![1]);
+        list.extend_from_slice(&[1, 2, 2]);
+        list.extend_from_slice(&[3, 3, 1]);
+        assert_eq!(*list.get(), [1, 1, 2, 2, 3, 3, 1]);
+        list.dedup();
+        assert_eq!(*list.get(), [1, 2, 3, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn remove_out_of_bounds() {
+        SuperCell::new(vec![1]).remove(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_out_of_bounds() {
+        SuperCell::new(vec![1]).insert(2, 0);
+    }
+
+    #[test]
+    fn reserve_exact_size_hint() {
+        struct Exact(u32);
+        impl Iterator for Exact {
+            type Item = u32;
+            fn next(&mut self) -> Option<u32> {
+                self.0 = self.0.checked_sub(1)?;
+                Some(self.0)
+            }
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.0 as usize, Some(self.0 as usize))
+            }
+        }
+        let list = SuperCell::new(Vec::new());
+        list.extend_from_iter(Exact(100));
+        assert_eq!(list.get().len(), 100);
+        // Growing one push at a time would have doubled past the exact length.
+        assert_eq!(list.get().capacity(), 100);
+    }
+}
@@ -0,0 +1,114 @@
+use alloc::rc::{self, Rc};
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::{self, Arc};
+use crate::SuperCell;
+
+/// Helpers for caching a weak reference to a shared resource
+///
+/// None of these are atomic, two threads racing on `upgrade_or_init` may both create a value
+/// and the last one to store its weak reference wins. Pair the cell with a lock if only one
+/// value may ever be created.
+#[cfg(target_has_atomic = "ptr")]
+impl<T> SuperCell<sync::Weak<T>> {
+    /// Upgrades the stored weak reference
+    #[inline]
+    pub fn upgrade(&self) -> Option<Arc<T>> {
+        self.get().upgrade()
+    }
+
+    /// Upgrades the stored weak reference, or creates a new value with `f` and stores a weak
+    /// reference to it if the old value is gone
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::{Arc, Weak};
+    /// use super_cell::SuperCell;
+    /// let cache = SuperCell::new(Weak::new());
+    /// let first = cache.upgrade_or_init(|| Arc::new(1));
+    /// let second = cache.upgrade_or_init(|| Arc::new(2));
+    /// assert!(Arc::ptr_eq(&first, &second));
+    /// ```
+    pub fn upgrade_or_init(&self, f : impl FnOnce() -> Arc<T>) -> Arc<T> {
+        if let Some(value) = self.upgrade() {
+            return value;
+        }
+        let value = f();
+        self.set(Arc::downgrade(&value));
+        value
+    }
+
+    /// Replaces the stored weak reference with an empty one
+    #[inline]
+    pub fn clear(&self) {
+        self.set(sync::Weak::new());
+    }
+}
+
+/// Single threaded counterpart of the `sync::Weak` helpers
+impl<T> SuperCell<rc::Weak<T>> {
+    /// Upgrades the stored weak reference
+    #[inline]
+    pub fn upgrade(&self) -> Option<Rc<T>> {
+        self.get().upgrade()
+    }
+
+    /// Upgrades the stored weak reference, or creates a new value with `f` and stores a weak
+    /// reference to it if the old value is gone
+    pub fn upgrade_or_init(&self, f : impl FnOnce() -> Rc<T>) -> Rc<T> {
+        if let Some(value) = self.upgrade() {
+            return value;
+        }
+        let value = f();
+        self.set(Rc::downgrade(&value));
+        value
+    }
+
+    /// Replaces the stored weak reference with an empty one
+    #[inline]
+    pub fn clear(&self) {
+        self.set(rc::Weak::new());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(target_has_atomic = "ptr")]
+    #[test]
+    fn arc_reinitializes_when_dead() {
+        let cache = SuperCell::new(sync::Weak::new());
+        let calls = SuperCell::new(0);
+        let init = || {
+            *calls.get_mut() += 1;
+            Arc::new(String::from("resource"))
+        };
+        let first = cache.upgrade_or_init(init);
+        assert!(Arc::ptr_eq(&cache.upgrade().expect("Weak should be live"), &first));
+        let second = cache.upgrade_or_init(|| unreachable!());
+        assert!(Arc::ptr_eq(&first, &second));
+        drop((first, second));
+        assert!(cache.upgrade().is_none());
+        let third = cache.upgrade_or_init(init);
+        assert!(Arc::ptr_eq(&cache.upgrade().expect("Weak should be live"), &third));
+        assert_eq!(*calls.get(), 2);
+        cache.clear();
+        assert!(cache.upgrade().is_none());
+    }
+
+    #[test]
+    fn rc_reinitializes_when_dead() {
+        let cache = SuperCell::new(rc::Weak::new());
+        let first = cache.upgrade_or_init(|| Rc::new(1));
+        assert!(Rc::ptr_eq(&cache.upgrade().expect("Weak should be live"), &first));
+        assert!(Rc::ptr_eq(&cache.upgrade_or_init(|| unreachable!()), &first));
+        drop(first);
+        let second = cache.upgrade_or_init(|| Rc::new(2));
+        assert_eq!(*second, 2);
+        assert!(Rc::ptr_eq(&cache.upgrade().expect("Weak should be live"), &second));
+        cache.clear();
+        assert!(cache.upgrade().is_none());
+        assert_eq!(Rc::weak_count(&second), 0);
+    }
+}
@@ -0,0 +1,133 @@
+//! Access patterns covered by the aliasing model, meant to be run with `cargo +nightly miri test`
+// These exercise the unchecked `get_mut` on purpose
+#![cfg_attr(feature = "strict", allow(deprecated))]
+
+use std::mem::ManuallyDrop;
+use std::thread;
+use super_cell::{project, SuperCell};
+
+static COUNTER : SuperCell<usize> = SuperCell::new(0);
+static TABLE : SuperCell<[u8; 4]> = SuperCell::new([0; 4]);
+
+#[test]
+fn sequential_mutable_references() {
+    let cell = SuperCell::new(vec![1, 2, 3]);
+    let first = cell.get_mut();
+    first.push(4);
+    let second = cell.get_mut();
+    second.push(5);
+    assert_eq!(*cell.get(), vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn slice_elements_are_disjoint() {
+    let mut values = [1, 2, 3, 4];
+    let cells = SuperCell::from_mut(&mut values[..]).as_slice_of_cells();
+    let (first, last) = (cells[0].get_mut(), cells[3].get_mut());
+    for _ in 0..3 {
+        *first += 1;
+        *last *= 2;
+    }
+    cells[1].set(cells[2].replace(0));
+    assert_eq!(values, [4, 3, 0, 32]);
+}
+
+#[test]
+fn slice_swaps() {
+    let cells = SuperCell::wrap_vec(vec![1, 2, 3]);
+    cells[0].set(cells[1].replace(cells[0].replace(0)) + cells[2].take());
+    assert_eq!(SuperCell::unwrap_vec(cells), vec![5, 1, 0]);
+}
+
+#[test]
+fn vec_as_slice_of_cells() {
+    let cell = SuperCell::new(vec![0usize; 4]);
+    for (index, element) in cell.as_slice_of_cells().iter().enumerate() {
+        element.with_mut(|value| *value = index * 2);
+    }
+    assert_eq!(*cell.get(), vec![0, 2, 4, 6]);
+}
+
+#[test]
+fn array_views() {
+    let cell = SuperCell::new([1u32, 2, 3]);
+    let [a, b, c] = cell.as_array_of_cells();
+    let (a, c) = (a.get_mut(), c.get_mut());
+    *a += *c;
+    *c = b.replace(*a);
+    assert_eq!(*cell.get(), [4, 4, 2]);
+}
+
+#[test]
+fn slice_casts() {
+    #[repr(transparent)]
+    struct Celsius(i32);
+    let mut readings = vec![20, 21, 22];
+    let cell = SuperCell::from_mut_slice(&mut readings);
+    // SAFETY: `Celsius` is `repr(transparent)` over `i32`.
+    let celsius = unsafe { cell.cast_slice::<Celsius>() };
+    let (first, last) = (celsius.as_slice_of_cells()[0].get_mut(), cell.as_slice_of_cells()[2].get_mut());
+    first.0 -= 20;
+    *last += 1;
+    // SAFETY: Every `i32` is a valid `u32` and back.
+    let bits = unsafe { cell.cast_slice::<u32>() };
+    bits.as_slice_of_cells()[1].set(7);
+    assert_eq!(readings, [0, 7, 23]);
+}
+
+#[test]
+fn statics_across_threads() {
+    let handles : Vec<_> = (0..4).map(|index| {
+        thread::spawn(move || TABLE.as_array_of_cells()[index].set(index as u8 + 1))
+    }).collect();
+    for handle in handles {
+        handle.join().expect("Failed to join thread!");
+    }
+    COUNTER.with_mut(|count| *count += TABLE.get().iter().map(|&value| value as usize).sum::<usize>());
+    assert_eq!(*TABLE.get(), [1, 2, 3, 4]);
+    assert_eq!(*COUNTER.get(), 10);
+}
+
+#[test]
+fn disjoint_projections() {
+    struct Pair {
+        left : Vec<u8>,
+        right : Vec<u8>
+    }
+    let cell = SuperCell::new(Pair { left: vec![], right: vec![] });
+    let left = project!(&cell => left).get_mut();
+    let right = project!(&cell => right).get_mut();
+    for value in 0..3 {
+        left.push(value);
+        right.push(value * 2);
+    }
+    assert_eq!(cell.get().left, [0, 1, 2]);
+    assert_eq!(cell.get().right, [0, 2, 4]);
+}
+
+#[test]
+fn raw_pointer_access() {
+    let cell = SuperCell::new(1);
+    let ptr = cell.as_ptr();
+    // SAFETY: No references into the cell are alive while the pointer is used.
+    unsafe {
+        *ptr += 1;
+        ptr.write(ptr.read() * 10);
+    }
+    assert_eq!(cell.into_inner(), 20);
+}
+
+#[test]
+fn manual_slots_reused() {
+    let slots : Vec<_> = (0..3).map(|index| SuperCell::new_manual(vec![index])).collect();
+    // SAFETY: Each slot is occupied when taken, and refilled before it is dropped in place.
+    unsafe {
+        let taken : Vec<_> = slots.iter().map(|slot| slot.take_manual()).collect();
+        for (slot, value) in slots.iter().zip(taken) {
+            slot.set(ManuallyDrop::new(value));
+        }
+        for slot in &slots {
+            slot.drop_in_place();
+        }
+    }
+}